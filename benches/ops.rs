@@ -26,10 +26,34 @@ fn rem(bench: &mut Bencher) {
     bench.iter(|| black_box(A) % black_box(B));
 }
 
+fn mul(bench: &mut Bencher) {
+    bench.iter(|| black_box(A).widening_mul(black_box(B)));
+}
+
+fn mul_large(bench: &mut Bencher) {
+    let a = Uint::<128>::new([0x9f678ffd; 128]);
+    let b = Uint::<128>::new([0x6b0094a1; 128]);
+    bench.iter(|| black_box(a).widening_mul(black_box(b)));
+}
+
+fn to_base_10(bench: &mut Bencher) {
+    let a = Uint::<16>::new([0x9f678ffd; 16]);
+    bench.iter(|| black_box(a).to_base_10_be().last());
+}
+
+fn to_base_10_large(bench: &mut Bencher) {
+    let a = Uint::<512>::new([0x9f678ffd; 512]);
+    bench.iter(|| black_box(a).to_base_10_be().last());
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("div_rem", div_rem);
     c.bench_function("div", div);
     c.bench_function("rem", rem);
+    c.bench_function("mul", mul);
+    c.bench_function("mul_large", mul_large);
+    c.bench_function("to_base_10", to_base_10);
+    c.bench_function("to_base_10_large", to_base_10_large);
 }
 
 criterion_group!(
@@ -1,10 +1,12 @@
 #![feature(generic_const_exprs)]
 
-use large::{Complex, Rational};
+use large::{Complex, Rational, Sign, Uint};
 
 type C = Complex<Rational<8>>;
 const ITERATIONS: usize = 15;
-const THRESHOLD_SQUARED: Rational<8> = Rational::from_u32(4);
+const THRESHOLD: Uint<8> = Uint::from_u32(2);
+const THRESHOLD_SQUARED: Rational<8> =
+    Rational::new(Sign::Pos, THRESHOLD.const_mul(THRESHOLD), Uint::ONE);
 
 fn main() {
     let viewport = (
@@ -1,6 +1,7 @@
-use std::{ops::{Add, Mul, Sub}, fmt::Display};
+use core::{ops::{Add, Mul, Sub}, fmt::Display};
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Complex<T> {
     pub r: T,
     pub i: T
@@ -38,7 +39,31 @@ impl<T> Mul for Complex<T> where T: Mul<Output = T> + Add<Output = T> + Sub<Outp
 }
 
 impl<T> Display for Complex<T> where T: Display {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} + {}i", self.r, self.i)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::{Complex, Rational, Sign, Uint};
+
+    #[test]
+    fn serde() {
+        let v = Complex {
+            r: Rational::<2>::new(Sign::Neg, Uint::from_u32(3), Uint::from_u32(4)),
+            i: Rational::<2>::new(Sign::Pos, Uint::from_u32(5), Uint::ONE),
+        };
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"r":"-3/4","i":"5/1"}"#);
+        let parsed: Complex<Rational<2>> = serde_json::from_str(&json).unwrap();
+        assert!(parsed.r == v.r && parsed.i == v.i);
+
+        let bytes = bincode::serialize(&v).unwrap();
+        let parsed: Complex<Rational<2>> = bincode::deserialize(&bytes).unwrap();
+        assert!(parsed.r == v.r && parsed.i == v.i);
+
+        assert_eq!(serde_json::to_string(&Sign::Neg).unwrap(), r#""Neg""#);
+        assert_eq!(serde_json::from_str::<Sign>(r#""Pos""#).unwrap(), Sign::Pos);
+    }
+}
@@ -0,0 +1,158 @@
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use super::Int;
+
+impl<const S: usize> Int<S> {
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let res = Self::from_bits(self.bits.wrapping_add(rhs.bits));
+        // overflow happens iff both operands have the same sign, but the result doesn't
+        let overflow =
+            self.is_negative() == rhs.is_negative() && res.is_negative() != self.is_negative();
+        (res, overflow)
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let res = Self::from_bits(self.bits.wrapping_sub(rhs.bits));
+        // overflow happens iff the operands have different signs, and the result has the sign of `rhs`
+        let overflow =
+            self.is_negative() != rhs.is_negative() && res.is_negative() != self.is_negative();
+        (res, overflow)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Negates `self`, overflowing if this is equal to the minimum value.
+    ///
+    /// Returns a tuple of the negated version of self along with a boolean indicating whether an
+    /// overflow happened.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (Self::from_bits(self.bits.wrapping_neg()), self == Self::MIN)
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` if `self == MIN`.
+    pub fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+}
+
+impl<const S: usize> Add for Int<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (res, overflow) = self.overflowing_add(rhs);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        res
+    }
+}
+
+impl<const S: usize> AddAssign for Int<S> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const S: usize> Sub for Int<S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (res, overflow) = self.overflowing_sub(rhs);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        res
+    }
+}
+
+impl<const S: usize> SubAssign for Int<S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const S: usize> Neg for Int<S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let (res, overflow) = self.overflowing_neg();
+        debug_assert!(!overflow, "attempt to negate with overflow");
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Int;
+
+    #[test]
+    fn against_i128() {
+        fn check(a: i128, b: i128) {
+            let (my_a, my_b) = (Int::<4>::from_i128(a), Int::<4>::from_i128(b));
+
+            let (res, o) = my_a.overflowing_add(my_b);
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_add(b));
+            assert_eq!(
+                my_a.checked_add(my_b).and_then(|r| r.to_i128()),
+                a.checked_add(b)
+            );
+
+            let (res, o) = my_a.overflowing_sub(my_b);
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_sub(b));
+            assert_eq!(
+                my_a.checked_sub(my_b).and_then(|r| r.to_i128()),
+                a.checked_sub(b)
+            );
+
+            let (res, o) = my_a.overflowing_neg();
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_neg());
+        }
+
+        let edge = [0, 1, -1, 2, -2, i128::MIN, i128::MAX, i128::MIN + 1];
+        for a in edge {
+            for b in edge {
+                check(a, b);
+            }
+        }
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(rand::random::<i64>() as i128, rand::random::<i64>() as i128);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Conversions between `Int` and `num_bigint::BigInt`.
+
+use num_bigint::BigInt;
+
+use super::{Int, TryFromIntError};
+use crate::{Sign, Uint};
+
+impl<const S: usize> From<Int<S>> for BigInt {
+    fn from(v: Int<S>) -> Self {
+        let (sign, magnitude) = v.to_sign_magnitude();
+        let magnitude = BigInt::from(magnitude);
+        match sign {
+            Sign::Pos => magnitude,
+            Sign::Neg => -magnitude,
+        }
+    }
+}
+
+impl<const S: usize> TryFrom<&BigInt> for Int<S> {
+    type Error = TryFromIntError;
+
+    /// Converts a `BigInt`, failing if it does not fit into `S` digits.
+    fn try_from(v: &BigInt) -> Result<Self, Self::Error> {
+        let sign = match v.sign() {
+            num_bigint::Sign::Minus => Sign::Neg,
+            _ => Sign::Pos,
+        };
+        let magnitude = Uint::try_from(v.magnitude())?;
+        Int::from_sign_magnitude(sign, magnitude).ok_or(TryFromIntError(()))
+    }
+}
+
+impl<const S: usize> TryFrom<BigInt> for Int<S> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: BigInt) -> Result<Self, Self::Error> {
+        Self::try_from(&v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use crate::{Int, TryFromIntError};
+
+    #[test]
+    fn against_i128() {
+        for _ in 0..100 {
+            let v = rand::random::<i128>();
+            assert_eq!(BigInt::from(Int::<4>::from_i128(v)), BigInt::from(v));
+            assert_eq!(Int::<4>::try_from(BigInt::from(v)), Ok(Int::from_i128(v)));
+        }
+        for v in [0, 1, -1, i128::MIN, i128::MAX] {
+            assert_eq!(BigInt::from(Int::<4>::from_i128(v)), BigInt::from(v));
+            assert_eq!(Int::<4>::try_from(BigInt::from(v)), Ok(Int::from_i128(v)));
+        }
+
+        let too_large = BigInt::from(i128::MAX) + 1;
+        assert_eq!(Int::<4>::try_from(&too_large), Err(TryFromIntError(())));
+        assert_eq!(
+            Int::<5>::try_from(&too_large).map(BigInt::from),
+            Ok(too_large)
+        );
+        let too_small = BigInt::from(i128::MIN) - 1;
+        assert_eq!(Int::<4>::try_from(too_small), Err(TryFromIntError(())));
+    }
+}
@@ -0,0 +1,224 @@
+use core::fmt;
+
+use super::Int;
+use crate::uint::{AssertMinSize, DigitBuf};
+use crate::{Sign, Uint};
+
+/// The error type returned when a checked integral type conversion fails.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TryFromIntError(pub(crate) ());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
+
+// The `from_*` functions reject sizes which are too small to hold every value of the primitive type
+// at compile time.
+impl<const S: usize> Int<S> {
+    pub const fn from_i32(v: i32) -> Self {
+        let () = AssertMinSize::<S, 1>::OK;
+        let mut data = [if v < 0 { u32::MAX } else { 0 }; S];
+        data[S - 1] = v as u32;
+        Self::from_bits(Uint::new(data))
+    }
+
+    pub const fn from_i64(v: i64) -> Self {
+        let () = AssertMinSize::<S, 2>::OK;
+        let mut data = [if v < 0 { u32::MAX } else { 0 }; S];
+        data[S - 1] = v as u32;
+        data[S - 2] = (v >> 32) as u32;
+        Self::from_bits(Uint::new(data))
+    }
+
+    pub const fn from_i128(v: i128) -> Self {
+        let () = AssertMinSize::<S, 4>::OK;
+        let mut data = [if v < 0 { u32::MAX } else { 0 }; S];
+        data[S - 1] = v as u32;
+        data[S - 2] = (v >> 32) as u32;
+        data[S - 3] = (v >> 64) as u32;
+        data[S - 4] = (v >> 96) as u32;
+        Self::from_bits(Uint::new(data))
+    }
+
+    pub fn to_i128(self) -> Option<i128> {
+        // the number fits if it is a sign-extended 128-bit number
+        let mut low = if self.is_negative() { -1i128 } else { 0 };
+        for i in 0..S.min(4) {
+            let digit = self.bits.as_limbs()[S - 1 - i] as i128;
+            low = low & !(0xffff_ffff << (32 * i)) | digit << (32 * i);
+        }
+        let mut data = [if low < 0 { u32::MAX } else { 0 }; S];
+        for i in 0..S.min(4) {
+            data[S - 1 - i] = (low >> (32 * i)) as u32;
+        }
+        if Self::from_bits(Uint::new(data)) == self {
+            Some(low)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a number from its sign and magnitude.
+    /// Returns `None` if the number is out of range.
+    pub fn from_sign_magnitude(sign: Sign, magnitude: Uint<S>) -> Option<Self> {
+        match sign {
+            Sign::Pos if magnitude < Self::MIN.bits => Some(Self::from_bits(magnitude)),
+            Sign::Neg if magnitude <= Self::MIN.bits => {
+                Some(Self::from_bits(magnitude.wrapping_neg()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the sign and the magnitude of this number. Zero is considered positive.
+    pub fn to_sign_magnitude(self) -> (Sign, Uint<S>) {
+        (self.sign(), self.unsigned_abs())
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $f:ident),*) => {
+        $(
+            impl<const S: usize> From<$t> for Int<S> {
+                fn from(v: $t) -> Self {
+                    Int::$f(v.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(i8 => from_i32, i16 => from_i32, i32 => from_i32, i64 => from_i64, i128 => from_i128);
+
+impl<const S: usize> TryFrom<Uint<S>> for Int<S> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Uint<S>) -> Result<Self, Self::Error> {
+        Int::from_sign_magnitude(Sign::Pos, v).ok_or(TryFromIntError(()))
+    }
+}
+
+impl<const S: usize> TryFrom<Int<S>> for Uint<S> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: Int<S>) -> Result<Self, Self::Error> {
+        if v.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(v.bits)
+        }
+    }
+}
+
+impl<const S: usize> TryFrom<(Sign, Uint<S>)> for Int<S> {
+    type Error = TryFromIntError;
+
+    fn try_from((sign, magnitude): (Sign, Uint<S>)) -> Result<Self, Self::Error> {
+        Int::from_sign_magnitude(sign, magnitude).ok_or(TryFromIntError(()))
+    }
+}
+
+impl<const S: usize> From<Int<S>> for (Sign, Uint<S>) {
+    fn from(v: Int<S>) -> Self {
+        v.to_sign_magnitude()
+    }
+}
+
+impl<const S: usize> fmt::Display for Int<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = DigitBuf::new();
+        let digits = self.unsigned_abs().encode_decimal(&mut buf);
+        f.pad_integral(!self.is_negative(), "", digits)
+    }
+}
+
+impl<const S: usize> fmt::LowerExp for Int<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.unsigned_abs().fmt_exp(f, !self.is_negative(), false)
+    }
+}
+
+impl<const S: usize> fmt::UpperExp for Int<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.unsigned_abs().fmt_exp(f, !self.is_negative(), true)
+    }
+}
+
+// like the primitive integers, these print the two's complement representation
+macro_rules! impl_fmt_bits {
+    ($($t:ident),*) => {
+        $(
+            impl<const S: usize> fmt::$t for Int<S> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$t::fmt(&self.bits, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_bits!(Binary, Octal, LowerHex, UpperHex);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Int, Sign, Uint};
+
+    #[test]
+    fn from_primitives() {
+        assert_eq!(Int::<1>::from(-1i8), Int::NEG_ONE);
+        assert_eq!(Int::<1>::from(i32::MIN), Int::MIN);
+        assert_eq!(Int::<2>::from(i64::MAX), Int::MAX);
+        assert_eq!(Int::<4>::from(i128::MIN), Int::MIN);
+        assert_eq!(Int::<6>::from(-5i16).to_i128(), Some(-5));
+        assert_eq!(Int::<6>::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!((Int::<6>::from(i128::MIN) - Int::ONE).to_i128(), None);
+        assert_eq!(Int::<1>::from(-7i32).to_i128(), Some(-7));
+    }
+
+    #[test]
+    fn uint() {
+        assert_eq!(Int::<2>::try_from(Uint::<2>::from_u32(5)), Ok(Int::from(5)));
+        assert!(Int::<2>::try_from(Uint::<2>::MAX).is_err());
+        assert_eq!(Uint::try_from(Int::<2>::MAX), Ok(Uint::MAX >> 1u64));
+        assert!(Uint::try_from(Int::<2>::NEG_ONE).is_err());
+    }
+
+    #[test]
+    fn sign_magnitude() {
+        let min = Int::<2>::MIN;
+        let (sign, magnitude) = min.into();
+        assert_eq!(sign, Sign::Neg);
+        assert_eq!(magnitude, Uint::ONE << 63u64);
+        assert_eq!(Int::try_from((sign, magnitude)), Ok(min));
+        assert!(Int::<2>::try_from((Sign::Pos, magnitude)).is_err());
+        assert_eq!(Int::<2>::ZERO.to_sign_magnitude(), (Sign::Pos, Uint::ZERO));
+        assert_eq!(Int::try_from((Sign::Neg, Uint::<2>::ZERO)), Ok(Int::ZERO));
+        assert_eq!(
+            Int::<2>::from(-3).to_sign_magnitude(),
+            (Sign::Neg, Uint::from_u32(3))
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Int::<4>::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(Int::<4>::from(12345).to_string(), "12345");
+        assert_eq!(Int::<4>::ZERO.to_string(), "0");
+
+        for v in [0, 1, -1, 1234, -98765, i128::MIN, i128::MAX] {
+            let my_v = Int::<4>::from(v);
+            assert_eq!(format!("{:+}", my_v), format!("{:+}", v));
+            assert_eq!(format!("{:>12}", my_v), format!("{:>12}", v));
+            assert_eq!(format!("{:08}", my_v), format!("{:08}", v));
+            assert_eq!(format!("{:e}", my_v), format!("{:e}", v));
+            assert_eq!(format!("{:.2E}", my_v), format!("{:.2E}", v));
+            assert_eq!(format!("{:#x}", my_v), format!("{:#x}", v));
+            assert_eq!(format!("{:b}", my_v), format!("{:b}", v));
+        }
+    }
+}
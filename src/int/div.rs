@@ -0,0 +1,289 @@
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+
+use super::Int;
+
+impl<const S: usize> Int<S>
+where
+    [(); S + 1]:,
+{
+    /// Divides `self` by `rhs`, returning `(self / rhs, self % rhs)`.
+    /// The quotient is rounded towards zero, the remainder has the sign of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero, or if the division overflows
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(
+            !(self == Self::MIN && rhs == Self::NEG_ONE),
+            "attempt to divide with overflow"
+        );
+        self.wrapping_div_rem(rhs)
+    }
+
+    // `MIN / -1` wraps around to `MIN`, with a remainder of zero.
+    fn wrapping_div_rem(self, rhs: Self) -> (Self, Self) {
+        let (q, r) = self.unsigned_abs().div_rem(rhs.unsigned_abs());
+        let (q, r) = (Self::from_bits(q), Self::from_bits(r));
+        let q = if self.is_negative() != rhs.is_negative() {
+            q.wrapping_neg()
+        } else {
+            q
+        };
+        let r = if self.is_negative() {
+            r.wrapping_neg()
+        } else {
+            r
+        };
+        (q, r)
+    }
+
+    /// Calculates `self / rhs`.
+    ///
+    /// Returns a tuple of the divisor along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would occur then self is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (
+            self.wrapping_div_rem(rhs).0,
+            self == Self::MIN && rhs == Self::NEG_ONE,
+        )
+    }
+
+    /// Calculates `self % rhs`.
+    ///
+    /// Returns a tuple of the remainder after dividing along with a boolean indicating whether an
+    /// arithmetic overflow would occur. If an overflow would occur then 0 is returned.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (
+            self.wrapping_div_rem(rhs).1,
+            self == Self::MIN && rhs == Self::NEG_ONE,
+        )
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0` or the
+    /// division results in overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        match self.overflowing_div(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0` or the
+    /// division results in overflow.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::ZERO {
+            return None;
+        }
+        match self.overflowing_rem(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the boundary of the type.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+    ///
+    /// This computes the integer `q` such that `self = q * rhs + r`, with
+    /// `r = self.rem_euclid(rhs)` and `0 <= r < abs(rhs)`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero, or if the division overflows
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r.is_negative() {
+            if rhs.is_negative() {
+                q + Self::ONE
+            } else {
+                q - Self::ONE
+            }
+        } else {
+            q
+        }
+    }
+
+    /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero, or if the division overflows
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r.is_negative() {
+            // this can't overflow: r + |rhs| is in [0, |rhs|)
+            r.wrapping_add(Self::from_bits(rhs.unsigned_abs()))
+        } else {
+            r
+        }
+    }
+
+    /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero, or if the division overflows
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        if r != Self::ZERO && r.is_negative() != rhs.is_negative() {
+            q - Self::ONE
+        } else {
+            q
+        }
+    }
+
+    /// Calculates the remainder of `self` and `rhs` with the quotient rounded towards negative
+    /// infinity. The result has the same sign as `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero, or if the division overflows
+    pub fn mod_floor(self, rhs: Self) -> Self {
+        let r = self % rhs;
+        if r != Self::ZERO && r.is_negative() != rhs.is_negative() {
+            r + rhs
+        } else {
+            r
+        }
+    }
+}
+
+impl<const S: usize> Div for Int<S>
+where
+    [(); S + 1]:,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<const S: usize> DivAssign for Int<S>
+where
+    [(); S + 1]:,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const S: usize> Rem for Int<S>
+where
+    [(); S + 1]:,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<const S: usize> RemAssign for Int<S>
+where
+    [(); S + 1]:,
+{
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_integer::Integer;
+
+    use crate::Int;
+
+    #[test]
+    fn against_i128() {
+        fn check(a: i128, b: i128) {
+            let (my_a, my_b) = (Int::<4>::from_i128(a), Int::<4>::from_i128(b));
+
+            assert_eq!(
+                my_a.checked_div(my_b).and_then(|r| r.to_i128()),
+                a.checked_div(b)
+            );
+            assert_eq!(
+                my_a.checked_rem(my_b).and_then(|r| r.to_i128()),
+                a.checked_rem(b)
+            );
+            if b == 0 {
+                return;
+            }
+            let (res, o) = my_a.overflowing_div(my_b);
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_div(b));
+            let (res, o) = my_a.overflowing_rem(my_b);
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_rem(b));
+            if o {
+                return;
+            }
+            assert_eq!(my_a.div_euclid(my_b).to_i128(), Some(a.div_euclid(b)));
+            assert_eq!(my_a.rem_euclid(my_b).to_i128(), Some(a.rem_euclid(b)));
+            assert_eq!(
+                my_a.div_floor(my_b).to_i128(),
+                Some(Integer::div_floor(&a, &b))
+            );
+            assert_eq!(
+                my_a.mod_floor(my_b).to_i128(),
+                Some(Integer::mod_floor(&a, &b))
+            );
+        }
+
+        let edge = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            3,
+            -3,
+            7,
+            -7,
+            i128::MIN,
+            i128::MAX,
+            i128::MIN + 1,
+        ];
+        for a in edge {
+            for b in edge {
+                check(a, b);
+            }
+        }
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random::<i64>() as i128);
+            check(rand::random::<i64>() as i128, rand::random::<i32>() as i128);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn div_overflow() {
+        let _ = Int::<4>::MIN / Int::NEG_ONE;
+    }
+}
@@ -0,0 +1,247 @@
+use core::cmp::Ordering;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
+
+use crate::{Sign, Uint};
+
+mod add;
+#[cfg(any(test, feature = "num-bigint"))]
+mod bigint;
+mod convert;
+mod div;
+mod mul;
+
+pub use convert::TryFromIntError;
+
+/// A signed integer with `S` digits of the base 2³², stored in two's complement.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Int<const S: usize> {
+    // two's complement representation - the MSB of bits.digits[0] is the sign bit
+    bits: Uint<S>,
+}
+
+impl<const S: usize> Int<S> {
+    pub const ZERO: Self = Self::from_bits(Uint::ZERO);
+    pub const ONE: Self = Self::from_bits(Uint::ONE);
+    pub const NEG_ONE: Self = Self::from_bits(Uint::MAX);
+    pub const MIN: Self = {
+        let mut digits = [0; S];
+        digits[0] = 1 << 31;
+        Self::from_bits(Uint::new(digits))
+    };
+    pub const MAX: Self = {
+        let mut digits = [u32::MAX; S];
+        digits[0] = u32::MAX >> 1;
+        Self::from_bits(Uint::new(digits))
+    };
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = Uint::<S>::BITS;
+
+    /// Creates a number from its two's complement representation.
+    pub const fn from_bits(bits: Uint<S>) -> Self {
+        Int { bits }
+    }
+
+    /// Returns the two's complement representation of this number.
+    pub const fn to_bits(self) -> Uint<S> {
+        self.bits
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.bits.as_limbs()[0] >> 31 == 1
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && *self != Self::ZERO
+    }
+
+    /// Returns a number representing sign of `self`: `0` if the number is zero,
+    /// `1` if the number is positive and `-1` if the number is negative
+    pub fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::NEG_ONE
+        } else if self == Self::ZERO {
+            Self::ZERO
+        } else {
+            Self::ONE
+        }
+    }
+
+    /// Returns the sign of this number. Zero is considered positive.
+    pub fn sign(&self) -> Sign {
+        if self.is_negative() {
+            Sign::Neg
+        } else {
+            Sign::Pos
+        }
+    }
+
+    /// Computes the absolute value of `self` without any wrapping or panicking.
+    pub fn unsigned_abs(self) -> Uint<S> {
+        if self.is_negative() {
+            self.bits.wrapping_neg()
+        } else {
+            self.bits
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// Returns a tuple of the absolute version of self along with a boolean indicating whether an
+    /// overflow happened. If self is the minimum value, then the minimum value will be returned
+    /// again and `true` will be returned for an overflow happening.
+    pub fn overflowing_abs(self) -> (Self, bool) {
+        (Self::from_bits(self.unsigned_abs()), self == Self::MIN)
+    }
+
+    /// Checked absolute value. Computes `self.abs()`, returning `None` if `self == MIN`.
+    pub fn checked_abs(self) -> Option<Self> {
+        match self.overflowing_abs() {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) absolute value. Computes `self.abs()`, wrapping around at the boundary of the type.
+    pub fn wrapping_abs(self) -> Self {
+        self.overflowing_abs().0
+    }
+
+    /// Computes the absolute value of `self`.
+    pub fn abs(self) -> Self {
+        let (res, overflow) = self.overflowing_abs();
+        debug_assert!(!overflow, "attempt to negate with overflow");
+        res
+    }
+}
+
+impl<const S: usize> PartialOrd for Int<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const S: usize> Ord for Int<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // flipping the sign bit maps MIN..=MAX to 0..=Uint::MAX, preserving the order
+        (self.bits ^ Self::MIN.bits).cmp(&(other.bits ^ Self::MIN.bits))
+    }
+}
+
+impl<const S: usize> Not for Int<S> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_bits(!self.bits)
+    }
+}
+
+macro_rules! impl_bit_ops {
+    ($($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $tok:tt;)*) => {
+        $(
+            impl<const S: usize> $op for Int<S> {
+                type Output = Self;
+
+                fn $op_fn(self, rhs: Self) -> Self::Output {
+                    Self::from_bits(self.bits $tok rhs.bits)
+                }
+            }
+
+            impl<const S: usize> $assign for Int<S> {
+                fn $assign_fn(&mut self, rhs: Self) {
+                    *self = *self $tok rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_ops! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &;
+    BitOr, bitor, BitOrAssign, bitor_assign, |;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^;
+}
+
+impl<const S: usize> Shl<u64> for Int<S> {
+    type Output = Self;
+
+    fn shl(self, rhs: u64) -> Self::Output {
+        Self::from_bits(self.bits << rhs)
+    }
+}
+
+impl<const S: usize> ShlAssign<u64> for Int<S> {
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const S: usize> Shr<u64> for Int<S> {
+    type Output = Self;
+
+    /// Arithmetic right shift - the sign bit is shifted in from the left.
+    fn shr(self, rhs: u64) -> Self::Output {
+        if self.is_negative() {
+            Self::from_bits(!(!self.bits >> rhs))
+        } else {
+            Self::from_bits(self.bits >> rhs)
+        }
+    }
+}
+
+impl<const S: usize> ShrAssign<u64> for Int<S> {
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = *self >> rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Int, Sign};
+
+    #[test]
+    fn constants() {
+        assert_eq!(Int::<4>::MIN.to_i128(), Some(i128::MIN));
+        assert_eq!(Int::<4>::MAX.to_i128(), Some(i128::MAX));
+        assert_eq!(Int::<4>::NEG_ONE.to_i128(), Some(-1));
+        assert_eq!(Int::<4>::BITS, 128);
+    }
+
+    #[test]
+    fn against_i128() {
+        fn check(a: i128, b: i128) {
+            let (my_a, my_b) = (Int::<4>::from_i128(a), Int::<4>::from_i128(b));
+            assert_eq!(my_a.cmp(&my_b), a.cmp(&b));
+            assert_eq!(my_a.signum().to_i128(), Some(a.signum()));
+            assert_eq!(my_a.is_positive(), a.is_positive());
+            assert_eq!(my_a.sign() == Sign::Neg, a.is_negative());
+            assert_eq!(my_a.unsigned_abs().to_u128(), Some(a.unsigned_abs()));
+            assert_eq!(
+                my_a.checked_abs().and_then(|r| r.to_i128()),
+                a.checked_abs()
+            );
+            assert_eq!(my_a.wrapping_abs().to_i128(), Some(a.wrapping_abs()));
+            assert_eq!((!my_a).to_i128(), Some(!a));
+            assert_eq!((my_a & my_b).to_i128(), Some(a & b));
+            assert_eq!((my_a | my_b).to_i128(), Some(a | b));
+            assert_eq!((my_a ^ my_b).to_i128(), Some(a ^ b));
+            for n in [0, 1, 31, 32, 33, 100, 127] {
+                assert_eq!((my_a << n as u64).to_i128(), Some(a << n));
+                assert_eq!((my_a >> n as u64).to_i128(), Some(a >> n));
+            }
+        }
+
+        let edge = [0, 1, -1, 2, -2, i128::MIN, i128::MAX, i128::MIN + 1];
+        for a in edge {
+            for b in edge {
+                check(a, b);
+            }
+        }
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(rand::random::<i64>() as i128, rand::random());
+        }
+    }
+}
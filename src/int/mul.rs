@@ -0,0 +1,92 @@
+use core::ops::{Mul, MulAssign};
+
+use super::Int;
+
+impl<const S: usize> Int<S> {
+    /// Calculates `self * rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        // the low bits of the product are the same for signed and unsigned numbers
+        let res = Self::from_bits(self.bits.wrapping_mul(rhs.bits));
+
+        let (magnitude, overflow) = self.unsigned_abs().overflowing_mul(rhs.unsigned_abs());
+        let fits = if self.is_negative() != rhs.is_negative() {
+            magnitude <= Self::MIN.bits
+        } else {
+            magnitude < Self::MIN.bits
+        };
+        (res, overflow || !fits)
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+}
+
+impl<const S: usize> Mul for Int<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (res, overflow) = self.overflowing_mul(rhs);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        res
+    }
+}
+
+impl<const S: usize> MulAssign for Int<S> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Int;
+
+    #[test]
+    fn against_i128() {
+        fn check(a: i128, b: i128) {
+            let (my_a, my_b) = (Int::<4>::from_i128(a), Int::<4>::from_i128(b));
+
+            let (res, o) = my_a.overflowing_mul(my_b);
+            assert_eq!((res.to_i128().unwrap(), o), a.overflowing_mul(b));
+            assert_eq!(
+                my_a.checked_mul(my_b).and_then(|r| r.to_i128()),
+                a.checked_mul(b)
+            );
+        }
+
+        let edge = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            i128::MIN,
+            i128::MAX,
+            i128::MIN + 1,
+            1 << 64,
+            -(1 << 63),
+        ];
+        for a in edge {
+            for b in edge {
+                check(a, b);
+            }
+        }
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(rand::random::<i64>() as i128, rand::random::<i64>() as i128);
+        }
+    }
+}
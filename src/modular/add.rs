@@ -0,0 +1,83 @@
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use super::{add, lt, sub, ModUint, Modulus};
+use crate::Uint;
+
+impl<const S: usize, M: Modulus<S>> Add for ModUint<S, M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let m = M::MODULUS.as_limbs();
+        let (sum, carry) = add(self.value.as_limbs(), rhs.value.as_limbs());
+        let sum = if carry || !lt(&sum, m) {
+            sub(&sum, m).0
+        } else {
+            sum
+        };
+        ModUint::from_montgomery(Uint::new(sum))
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> AddAssign for ModUint<S, M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Sub for ModUint<S, M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = sub(self.value.as_limbs(), rhs.value.as_limbs());
+        let diff = if borrow {
+            add(&diff, M::MODULUS.as_limbs()).0
+        } else {
+            diff
+        };
+        ModUint::from_montgomery(Uint::new(diff))
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> SubAssign for ModUint<S, M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Neg for ModUint<S, M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::modular::tests::{Full, Small, P127};
+    use crate::modular::{ModUint, Modulus};
+    use crate::Uint;
+
+    #[test]
+    fn against_num_bigint() {
+        fn check<const S: usize, M: Modulus<S>>(a: Uint<S>, b: Uint<S>) {
+            let m = BigUint::from(M::MODULUS);
+            let (c_a, c_b) = (BigUint::from(a) % &m, BigUint::from(b) % &m);
+            let (my_a, my_b) = (ModUint::<S, M>::new(a), ModUint::<S, M>::new(b));
+
+            assert_eq!((my_a + my_b).retrieve(), (&c_a + &c_b) % &m);
+            assert_eq!((my_a - my_b).retrieve(), (&c_a + &m - &c_b) % &m);
+            assert_eq!((-my_a).retrieve(), (&m - &c_a) % &m);
+        }
+
+        for _ in 0..500 {
+            check::<4, P127>(Uint::from_u128(rand::random()), Uint::from_u128(rand::random()));
+            check::<8, Full>(Uint::new(rand::random()), Uint::new(rand::random()));
+            check::<8, Small>(Uint::new(rand::random()), Uint::new(rand::random()));
+        }
+        check::<8, Full>(Uint::MAX, Uint::MAX);
+        check::<8, Full>(Uint::ZERO, Uint::MAX);
+    }
+}
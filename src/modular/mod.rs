@@ -0,0 +1,362 @@
+use core::fmt::{self, Debug, Display};
+use core::marker::PhantomData;
+
+use crate::Uint;
+
+mod add;
+mod mul;
+
+/// A modulus for [`ModUint`].
+///
+/// ```
+/// use large::{ModUint, Modulus, Uint};
+///
+/// struct P;
+/// impl Modulus<4> for P {
+///     const MODULUS: Uint<4> = Uint::from_u128(u128::MAX >> 1);
+/// }
+///
+/// let x = ModUint::<4, P>::new(Uint::from_u128(u128::MAX));
+/// assert_eq!((x * x).retrieve(), Uint::ONE);
+/// ```
+pub trait Modulus<const S: usize> {
+    /// The modulus. It must be odd.
+    const MODULUS: Uint<S>;
+}
+
+/// An integer modulo `M::MODULUS`, stored in Montgomery form.
+///
+/// Instead of `x`, `x * R mod m` with `R = 2^(32 * S)` is stored. This allows multiplication
+/// without any division - only the precomputed constants `R^2 mod m` and `-m^-1 mod 2^32` are needed.
+pub struct ModUint<const S: usize, M: Modulus<S>> {
+    // x * R mod m
+    value: Uint<S>,
+    modulus: PhantomData<M>,
+}
+
+impl<const S: usize, M: Modulus<S>> ModUint<S, M> {
+    // -m^-1 mod 2^32
+    const NEG_INV: u32 = neg_inv(M::MODULUS.as_limbs()[S - 1]);
+    // R mod m, which is 1 in Montgomery form
+    const R: Uint<S> = Uint::new(r_mod(M::MODULUS.as_limbs()));
+    // R^2 mod m, which is R in Montgomery form
+    const R2: Uint<S> = Uint::new(r2_mod(
+        M::MODULUS.as_limbs(),
+        Self::R.as_limbs(),
+        Self::NEG_INV,
+    ));
+    // R^3 mod m, which is R^2 in Montgomery form. Used to bring inverses back into Montgomery form.
+    const R3: Uint<S> = Uint::new(mont_mul(
+        Self::R2.as_limbs(),
+        Self::R2.as_limbs(),
+        M::MODULUS.as_limbs(),
+        Self::NEG_INV,
+    ));
+
+    pub const ZERO: Self = Self::from_montgomery(Uint::ZERO);
+    pub const ONE: Self = Self::from_montgomery(Self::R);
+    pub const MODULUS: Uint<S> = M::MODULUS;
+
+    /// Creates a new number, reducing `v` modulo `M::MODULUS`.
+    pub const fn new(v: Uint<S>) -> Self {
+        // v * R^2 * R^-1 = v * R
+        // this works for `v >= m` as well, since `v * R^2 < m * R`.
+        Self::from_montgomery(Uint::new(mont_mul(
+            v.as_limbs(),
+            Self::R2.as_limbs(),
+            M::MODULUS.as_limbs(),
+            Self::NEG_INV,
+        )))
+    }
+
+    /// Returns the value of this number as a plain `Uint`, which is always less than the modulus.
+    pub const fn retrieve(self) -> Uint<S> {
+        // x * R * R^-1 = x
+        Uint::new(mont_mul(
+            self.value.as_limbs(),
+            Uint::<S>::ONE.as_limbs(),
+            M::MODULUS.as_limbs(),
+            Self::NEG_INV,
+        ))
+    }
+
+    /// Returns the internal representation of this number, `x * R mod m`.
+    pub const fn as_montgomery(&self) -> &Uint<S> {
+        &self.value
+    }
+
+    /// Creates a number from its internal representation `x * R mod m`.
+    /// `value` must be less than the modulus.
+    pub const fn from_montgomery(value: Uint<S>) -> Self {
+        ModUint {
+            value,
+            modulus: PhantomData,
+        }
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Clone for ModUint<S, M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Copy for ModUint<S, M> {}
+
+impl<const S: usize, M: Modulus<S>> PartialEq for ModUint<S, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Eq for ModUint<S, M> {}
+
+impl<const S: usize, M: Modulus<S>> Debug for ModUint<S, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModUint")
+            .field("value", &self.retrieve())
+            .field("modulus", &M::MODULUS)
+            .finish()
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> Display for ModUint<S, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.retrieve(), f)
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> From<Uint<S>> for ModUint<S, M> {
+    fn from(v: Uint<S>) -> Self {
+        ModUint::new(v)
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> From<ModUint<S, M>> for Uint<S> {
+    fn from(v: ModUint<S, M>) -> Self {
+        v.retrieve()
+    }
+}
+
+// The functions below work on the big-endian digits of a `Uint`, and are `const` so that the
+// Montgomery constants can be computed at compile time.
+
+// calculates -m^-1 mod 2^32 using newton iteration. Each iteration doubles the number of correct bits.
+pub(crate) const fn neg_inv(m0: u32) -> u32 {
+    assert!(m0 & 1 == 1, "the modulus must be odd");
+    let mut inv = 1u32;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(m0.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+// calculates R mod m by doubling the largest power of two below m until we reach R.
+pub(crate) const fn r_mod<const S: usize>(m: &[u32; S]) -> [u32; S] {
+    let mut bits = S as u32 * 32;
+    let mut i = 0;
+    while i < S && m[i] == 0 {
+        bits -= 32;
+        i += 1;
+    }
+    bits -= m[i].leading_zeros();
+
+    let mut x = [0u32; S];
+    if bits == 1 {
+        // m == 1
+        return x;
+    }
+    // x = 2^(bits - 1) < m
+    x[S - 1 - (bits as usize - 1) / 32] = 1 << ((bits - 1) % 32);
+    let mut k = bits - 1;
+    while k < S as u32 * 32 {
+        x = double_mod(&x, m);
+        k += 1;
+    }
+    x
+}
+
+// calculates R^2 mod m, which is R in Montgomery form.
+// 2 in Montgomery form is doubled R mod m, which is then raised to the power of 32 * S.
+pub(crate) const fn r2_mod<const S: usize>(m: &[u32; S], r: &[u32; S], neg_inv: u32) -> [u32; S] {
+    let two = double_mod(r, m);
+    let exp = S as u32 * 32;
+    let mut acc = *r;
+    let mut bit = 32 - exp.leading_zeros();
+    while bit > 0 {
+        bit -= 1;
+        acc = mont_mul(&acc, &acc, m, neg_inv);
+        if exp & (1 << bit) != 0 {
+            acc = mont_mul(&acc, &two, m, neg_inv);
+        }
+    }
+    acc
+}
+
+// calculates 2x mod m for x < m
+const fn double_mod<const S: usize>(x: &[u32; S], m: &[u32; S]) -> [u32; S] {
+    let (x, carry) = add(x, x);
+    if carry || !lt(&x, m) {
+        sub(&x, m).0
+    } else {
+        x
+    }
+}
+
+// Montgomery multiplication: calculates a * b * R^-1 mod m, using the CIOS method.
+// the result is less than m if a * b < m * R.
+pub(crate) const fn mont_mul<const S: usize>(
+    a: &[u32; S],
+    b: &[u32; S],
+    m: &[u32; S],
+    neg_inv: u32,
+) -> [u32; S] {
+    // the accumulator has S + 2 digits - `t`, followed by `t_hi` and `t_hi2`
+    let mut t = [0u32; S];
+    let mut t_hi = 0u32;
+
+    let mut i = 0;
+    while i < S {
+        // t += a * b[i]
+        let b_i = b[S - 1 - i] as u64;
+        let mut carry = 0u64;
+        let mut j = S;
+        while j > 0 {
+            j -= 1;
+            let x = t[j] as u64 + a[j] as u64 * b_i + carry;
+            t[j] = x as u32;
+            carry = x >> 32;
+        }
+        let x = t_hi as u64 + carry;
+        t_hi = x as u32;
+        let t_hi2 = (x >> 32) as u32;
+
+        // t = (t + q * m) / 2^32, where q is chosen so that the LSD becomes 0
+        let q = t[S - 1].wrapping_mul(neg_inv) as u64;
+        let mut carry = (t[S - 1] as u64 + q * m[S - 1] as u64) >> 32;
+        let mut j = S - 1;
+        while j > 0 {
+            j -= 1;
+            let x = t[j] as u64 + q * m[j] as u64 + carry;
+            t[j + 1] = x as u32;
+            carry = x >> 32;
+        }
+        let x = t_hi as u64 + carry;
+        t[0] = x as u32;
+        t_hi = t_hi2 + (x >> 32) as u32;
+
+        i += 1;
+    }
+
+    // t < 2m
+    if t_hi != 0 || !lt(&t, m) {
+        t = sub(&t, m).0;
+    }
+    t
+}
+
+pub(crate) const fn add<const S: usize>(a: &[u32; S], b: &[u32; S]) -> ([u32; S], bool) {
+    let mut out = [0u32; S];
+    let mut carry = 0u64;
+    let mut i = S;
+    while i > 0 {
+        i -= 1;
+        let x = a[i] as u64 + b[i] as u64 + carry;
+        out[i] = x as u32;
+        carry = x >> 32;
+    }
+    (out, carry != 0)
+}
+
+pub(crate) const fn sub<const S: usize>(a: &[u32; S], b: &[u32; S]) -> ([u32; S], bool) {
+    let mut out = [0u32; S];
+    let mut borrow = false;
+    let mut i = S;
+    while i > 0 {
+        i -= 1;
+        let (x, b1) = a[i].overflowing_sub(b[i]);
+        let (x, b2) = x.overflowing_sub(borrow as u32);
+        out[i] = x;
+        borrow = b1 || b2;
+    }
+    (out, borrow)
+}
+
+pub(crate) const fn lt<const S: usize>(a: &[u32; S], b: &[u32; S]) -> bool {
+    let mut i = 0;
+    while i < S {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::{ModUint, Modulus};
+    use crate::Uint;
+
+    // 2^127 - 1
+    #[derive(Copy, Clone, Debug)]
+    pub(super) struct P127;
+    impl Modulus<4> for P127 {
+        const MODULUS: Uint<4> = Uint::from_u128(u128::MAX >> 1);
+    }
+
+    // some odd, composite number filling all digits
+    #[derive(Copy, Clone, Debug)]
+    pub(super) struct Full;
+    impl Modulus<8> for Full {
+        const MODULUS: Uint<8> = Uint::new([
+            0xf1a2b3c4, 0xd5e6f708, 0x192a3b4c, 0x5d6e7f80, 0x91a2b3c4, 0xd5e6f708, 0x192a3b4c,
+            0x5d6e7f81,
+        ]);
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    pub(super) struct Small;
+    impl Modulus<8> for Small {
+        const MODULUS: Uint<8> = Uint::from_u32(1_000_000_007);
+    }
+
+    #[test]
+    fn constants() {
+        fn check<const S: usize, M: Modulus<S>>() {
+            let m = BigUint::from(M::MODULUS);
+            let r = BigUint::from(1u32) << (32 * S);
+            assert_eq!(ModUint::<S, M>::R, &r % &m);
+            assert_eq!(ModUint::<S, M>::R2, (&r * &r) % &m);
+            assert_eq!(ModUint::<S, M>::R3, (&r * &r * &r) % &m);
+            let neg_inv = ModUint::<S, M>::NEG_INV;
+            assert_eq!(M::MODULUS.as_limbs()[S - 1].wrapping_mul(neg_inv), u32::MAX);
+        }
+
+        check::<4, P127>();
+        check::<8, Full>();
+        check::<8, Small>();
+    }
+
+    #[test]
+    fn round_trip() {
+        fn check<const S: usize, M: Modulus<S>>(v: Uint<S>) {
+            let m = BigUint::from(M::MODULUS);
+            assert_eq!(ModUint::<S, M>::new(v).retrieve(), BigUint::from(v) % m);
+        }
+
+        for _ in 0..100 {
+            check::<4, P127>(Uint::from_u128(rand::random()));
+            check::<8, Full>(Uint::new(rand::random()));
+            check::<8, Small>(Uint::new(rand::random()));
+        }
+        check::<8, Full>(Uint::MAX);
+        check::<8, Full>(Full::MODULUS);
+        assert_eq!(ModUint::<8, Full>::ONE.retrieve(), Uint::ONE);
+        assert_eq!(ModUint::<8, Full>::ZERO.retrieve(), Uint::ZERO);
+    }
+}
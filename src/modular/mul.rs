@@ -0,0 +1,173 @@
+use core::ops::{Mul, MulAssign};
+
+use super::{mont_mul, ModUint, Modulus};
+use crate::Uint;
+
+impl<const S: usize, M: Modulus<S>> Mul for ModUint<S, M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a * R) * (b * R) * R^-1 = a * b * R
+        ModUint::from_montgomery(Uint::new(mont_mul(
+            self.value.as_limbs(),
+            rhs.value.as_limbs(),
+            M::MODULUS.as_limbs(),
+            Self::NEG_INV,
+        )))
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> MulAssign for ModUint<S, M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const S: usize, M: Modulus<S>> ModUint<S, M> {
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    pub fn pow(self, exp: Uint<S>) -> Self {
+        let mut result = Self::ONE;
+        for i in (0..exp.bits()).rev() {
+            result = result * result;
+            if exp.bit(i) {
+                result *= self;
+            }
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of `self`, or `None` if `self` and the modulus
+    /// are not coprime.
+    ///
+    /// For the modulus 1, every number equals `ZERO` and `ONE`, so `Some(ZERO)` is returned.
+    pub fn inv(self) -> Option<Self> {
+        // binary extended euclidean algorithm on the Montgomery form `a = x * R`, which yields
+        // `a^-1 = x^-1 * R^-1`. Since the modulus is odd, halving `x1` and `x2` modulo m is
+        // always possible.
+        // invariants: x1 * a = u (mod m) and x2 * a = v (mod m)
+        let m = M::MODULUS;
+        if m == Uint::ONE {
+            return Some(Self::ZERO);
+        }
+        let (mut u, mut v) = (self.value, m);
+        let (mut x1, mut x2) = (Uint::<S>::ONE, Uint::<S>::ZERO);
+
+        if u == Uint::ZERO {
+            return None;
+        }
+        while u != Uint::ONE && v != Uint::ONE {
+            if u == Uint::ZERO || v == Uint::ZERO {
+                return None;
+            }
+            while u.is_even() {
+                u >>= 1;
+                x1 = half_mod(x1, m);
+            }
+            while v.is_even() {
+                v >>= 1;
+                x2 = half_mod(x2, m);
+            }
+            if u >= v {
+                u -= v;
+                x1 = sub_mod(x1, x2, m);
+            } else {
+                v -= u;
+                x2 = sub_mod(x2, x1, m);
+            }
+        }
+
+        // x^-1 * R^-1 * R^3 * R^-1 = x^-1 * R
+        let inv = if u == Uint::ONE { x1 } else { x2 };
+        Some(Self::from_montgomery(Uint::new(mont_mul(
+            inv.as_limbs(),
+            Self::R3.as_limbs(),
+            m.as_limbs(),
+            Self::NEG_INV,
+        ))))
+    }
+}
+
+// calculates a - b mod m for a, b < m
+fn sub_mod<const S: usize>(a: Uint<S>, b: Uint<S>, m: Uint<S>) -> Uint<S> {
+    let (diff, borrow) = a.overflowing_sub(b);
+    if borrow {
+        diff.wrapping_add(m)
+    } else {
+        diff
+    }
+}
+
+// calculates x / 2 mod m for odd m and x < m
+fn half_mod<const S: usize>(x: Uint<S>, m: Uint<S>) -> Uint<S> {
+    if x.is_even() {
+        return x >> 1;
+    }
+    // x + m is even. If it overflows, the carry becomes the MSB after shifting.
+    let (sum, carry) = x.overflowing_add(m);
+    let mut half = sum >> 1;
+    if carry {
+        half.set_bit(Uint::<S>::BITS - 1);
+    }
+    half
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::modular::tests::{Full, Small, P127};
+    use crate::modular::{ModUint, Modulus};
+    use crate::Uint;
+
+    #[test]
+    fn against_num_bigint() {
+        fn check<const S: usize, M: Modulus<S>>(a: Uint<S>, b: Uint<S>) {
+            let m = BigUint::from(M::MODULUS);
+            let (c_a, c_b) = (BigUint::from(a) % &m, BigUint::from(b) % &m);
+            let (my_a, my_b) = (ModUint::<S, M>::new(a), ModUint::<S, M>::new(b));
+
+            assert_eq!((my_a * my_b).retrieve(), (&c_a * &c_b) % &m);
+            assert_eq!(my_a.pow(b).retrieve(), BigUint::from(a).modpow(&BigUint::from(b), &m));
+        }
+
+        for _ in 0..200 {
+            check::<4, P127>(Uint::from_u128(rand::random()), Uint::from_u128(rand::random()));
+            check::<8, Full>(Uint::new(rand::random()), Uint::new(rand::random()));
+            check::<8, Small>(Uint::new(rand::random()), Uint::new(rand::random()));
+        }
+        check::<8, Full>(Uint::MAX, Uint::MAX);
+        check::<8, Full>(Uint::ZERO, Uint::ZERO);
+    }
+
+    #[test]
+    fn inv() {
+        fn check<const S: usize, M: Modulus<S>>(a: Uint<S>) {
+            let a = ModUint::<S, M>::new(a);
+            match a.inv() {
+                Some(inv) => assert_eq!(a * inv, ModUint::ONE),
+                None => {
+                    let m = BigUint::from(M::MODULUS);
+                    let gcd = num_integer::Integer::gcd(&BigUint::from(a.retrieve()), &m);
+                    assert_ne!(gcd, BigUint::from(1u32));
+                }
+            }
+        }
+
+        for _ in 0..200 {
+            check::<4, P127>(Uint::from_u128(rand::random()));
+            check::<8, Full>(Uint::new(rand::random()));
+            check::<8, Small>(Uint::new(rand::random()));
+        }
+        assert_eq!(ModUint::<8, Full>::ZERO.inv(), None);
+        assert_eq!(ModUint::<8, Full>::ONE.inv(), Some(ModUint::ONE));
+        // the modulus is divisible by 13
+        assert_eq!(ModUint::<8, Full>::new(Uint::from_u32(13)).inv(), None);
+        assert_eq!(ModUint::<8, Full>::new(Uint::from_u32(26)).inv(), None);
+
+        struct One;
+        impl Modulus<2> for One {
+            const MODULUS: Uint<2> = Uint::ONE;
+        }
+        assert_eq!(ModUint::<2, One>::ZERO.inv(), Some(ModUint::ZERO));
+    }
+}
@@ -1,10 +1,10 @@
-use std::{
+use core::{
     mem::swap,
-    ops::{Add, AddAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
 };
 
 use super::Rational;
-use crate::{rational::to_same_denominator, Sign::*};
+use crate::{rational::to_same_denominator, Sign::*, Uint};
 
 impl<const S: usize> Add for Rational<S>
 where
@@ -85,6 +85,18 @@ where
     }
 }
 
+impl<const S: usize> Neg for Rational<S> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        // zero stays positive
+        if self.num != Uint::ZERO {
+            self.sign = self.sign * Neg;
+        }
+        self
+    }
+}
+
 fn do_add_positive<const S: usize>(a: Rational<S>, b: Rational<S>) -> Rational<S>
 where
     [(); S + 1]:,
@@ -93,9 +105,7 @@ where
     assert!(a.sign == Pos && b.sign == Pos);
 
     // in the worst case, we need 2s space
-    let mut a: Rational<{ 2 * S }> = a.resized();
-    let mut b: Rational<{ 2 * S }> = b.resized();
-    to_same_denominator(&mut a, &mut b);
+    let (mut a, b) = to_same_denominator(a, b);
 
     a.num += b.num;
     a.reduced().resized()
@@ -116,10 +126,8 @@ where
         Pos
     };
 
-    // in the worst case, we need s^2 spaces
-    let mut a: Rational<{ 2 * S }> = a.resized();
-    let mut b: Rational<{ 2 * S }> = b.resized();
-    to_same_denominator(&mut a, &mut b);
+    // in the worst case, we need 2s space
+    let (mut a, b) = to_same_denominator(a, b);
 
     a.num -= b.num;
     a.sign = sign;
@@ -128,10 +136,11 @@ where
 
 #[cfg(test)]
 mod tests {
+    use num_bigint::BigUint;
     use num_rational::BigRational;
 
     use crate::rational::Rational;
-    use crate::{Uint, Sign::*};
+    use crate::{Sign::*, Uint};
 
     #[test]
     fn trivial() {
@@ -176,6 +185,18 @@ mod tests {
             Op::Add => (a + b, c_a + c_b),
             Op::Sub => (a - b, c_a - c_b),
         };
-        assert_eq!(this, c);
+        // the result must be reduced and zero must be positive, just like a `BigRational`
+        let sign = match c.numer().sign() {
+            num_bigint::Sign::Minus => Neg,
+            _ => Pos,
+        };
+        assert_eq!(
+            (this.sign, BigUint::from(this.num), BigUint::from(this.den)),
+            (
+                sign,
+                c.numer().magnitude().clone(),
+                c.denom().magnitude().clone()
+            )
+        );
     }
 }
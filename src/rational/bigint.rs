@@ -0,0 +1,132 @@
+//! Conversions between `Rational` and `num_rational::BigRational`.
+
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+
+use crate::{Sign, TryFromIntError, Uint};
+
+use super::Rational;
+
+impl<const S: usize> From<Rational<S>> for BigRational {
+    /// Converts a `Rational`. The result is reduced, like every `BigRational`.
+    fn from(v: Rational<S>) -> Self {
+        let num = BigInt::from(v.num);
+        let num = match v.sign {
+            Sign::Pos => num,
+            Sign::Neg => -num,
+        };
+        BigRational::new(num, BigInt::from(v.den))
+    }
+}
+
+impl<const S: usize> TryFrom<&BigRational> for Rational<S>
+where
+    [(); S + 1]:,
+{
+    type Error = TryFromIntError;
+
+    /// Converts a `BigRational`, failing if the numerator or the denominator does not fit into `S`
+    /// digits.
+    fn try_from(v: &BigRational) -> Result<Self, Self::Error> {
+        let (num, den) = (v.numer(), v.denom());
+        let sign = match num.sign() * den.sign() {
+            num_bigint::Sign::Minus => Sign::Neg,
+            _ => Sign::Pos,
+        };
+        let den = Uint::try_from(den.magnitude())?;
+        if den == Uint::ZERO {
+            return Err(TryFromIntError(()));
+        }
+        Ok(Rational::new(sign, Uint::try_from(num.magnitude())?, den))
+    }
+}
+
+impl<const S: usize> TryFrom<BigRational> for Rational<S>
+where
+    [(); S + 1]:,
+{
+    type Error = TryFromIntError;
+
+    fn try_from(v: BigRational) -> Result<Self, Self::Error> {
+        Self::try_from(&v)
+    }
+}
+
+impl<const S: usize> PartialEq<BigRational> for Rational<S> {
+    /// Compares the values, so the fractions don't need to be reduced.
+    fn eq(&self, other: &BigRational) -> bool {
+        let (num, den) = (other.numer(), other.denom());
+        if self.num == Uint::ZERO || num.sign() == num_bigint::Sign::NoSign {
+            // zero may have either sign
+            return self.num == Uint::ZERO && num.sign() == num_bigint::Sign::NoSign;
+        }
+        let sign = match num.sign() * den.sign() {
+            num_bigint::Sign::Minus => Sign::Neg,
+            _ => Sign::Pos,
+        };
+        // a/b == c/d  <=>  a * d == c * b
+        self.sign == sign
+            && BigUint::from(self.num) * den.magnitude()
+                == BigUint::from(self.den) * num.magnitude()
+    }
+}
+
+impl<const S: usize> PartialEq<Rational<S>> for BigRational {
+    fn eq(&self, other: &Rational<S>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    use crate::{Rational, Sign, TryFromIntError, Uint};
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..100 {
+            let sign = if rand::random() { Sign::Pos } else { Sign::Neg };
+            let den = Uint::<4>::from_u128(rand::random::<u128>() | 1);
+            let v = Rational::new(sign, Uint::from_u128(rand::random()), den);
+            let big = BigRational::from(v);
+            assert_eq!(v, big);
+            assert_eq!(
+                Rational::<4>::try_from(&big).map(BigRational::from),
+                Ok(big)
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        for sign in [Sign::Pos, Sign::Neg] {
+            let zero = Rational::<2>::new(sign, Uint::ZERO, Uint::from_u32(7));
+            assert_eq!(zero, BigRational::from(BigInt::from(0)));
+            assert_eq!(BigRational::from(zero), BigRational::from(BigInt::from(0)));
+            // agrees with the comparison of `Rational`s
+            assert_eq!(zero, Rational::ZERO);
+        }
+        let zero = Rational::<2>::try_from(BigRational::from(BigInt::from(0))).unwrap();
+        assert_eq!(
+            (zero.sign, zero.num, zero.den),
+            (Sign::Pos, Uint::ZERO, Uint::ONE)
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let v = BigRational::new(BigInt::from(-3), BigInt::from(1u64 << 32));
+        assert_eq!(
+            Rational::<1>::try_from(&v).map(|_| ()),
+            Err(TryFromIntError(()))
+        );
+        let converted = Rational::<2>::try_from(&v).unwrap();
+        assert_eq!(converted.sign, Sign::Neg);
+        assert_eq!(converted, v);
+        let raw = BigRational::new_raw(BigInt::from(1), BigInt::from(0));
+        assert!(Rational::<2>::try_from(raw).is_err());
+        assert_ne!(Rational::<2>::ONE, BigRational::from(BigInt::from(2)));
+    }
+}
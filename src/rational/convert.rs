@@ -1,5 +1,6 @@
-use std::{fmt::Debug, fmt::Display};
+use core::fmt::{self, Debug, Display, Write};
 
+use crate::uint::{write_pre_padding, DigitBuf};
 use crate::{Uint, Sign};
 
 use super::Rational;
@@ -18,17 +19,101 @@ where
 }
 
 impl<const S: usize> Debug for Rational<S> where [(); S + 1]: {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Rational {{ sign: {:?}, num: {}, den: {} }}", self.sign, self.num, self.den)
     }
 }
 
+/// Without a precision, this prints the fraction `num/den`. With a precision, the number is printed
+/// as a decimal fraction with that many digits after the point, rounded half to even.
 impl<const S: usize> Display for Rational<S> where [(); S + 1]: {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.sign == Sign::Neg {
-            write!(f, "-")?;
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => self.fmt_decimal(f, precision),
+            None => self.fmt_fraction(f),
         }
-        write!(f, "{}/{}", self.num, self.den)
+    }
+}
+
+impl<const S: usize> Rational<S> where [(); S + 1]: {
+    fn fmt_fraction(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (mut num_buf, mut den_buf) = (DigitBuf::new(), DigitBuf::new());
+        let num = self.num.encode_decimal(&mut num_buf);
+        let den = self.den.encode_decimal(&mut den_buf);
+
+        let post = write_pre_padding(f, self.sign == Sign::Pos, "", num.len() + 1 + den.len())?;
+        f.write_str(num)?;
+        f.write_char('/')?;
+        f.write_str(den)?;
+        post.write(f)
+    }
+
+    fn fmt_decimal(&self, f: &mut fmt::Formatter<'_>, precision: usize) -> fmt::Result {
+        let (int, rem) = self.num.div_rem(self.den);
+
+        // rounding the last digit up can carry over into the digits in front of it, so the
+        // fractional digits are generated twice: once to decide on the rounding, and once to print.
+        let mut digits = FractionDigits::new(rem, self.den);
+        let mut last_odd = int.is_odd();
+        let mut last_below_nine = None;
+        for i in 0..precision {
+            let digit = digits.next_digit();
+            last_odd = digit % 2 == 1;
+            if digit != 9 {
+                last_below_nine = Some(i);
+            }
+        }
+        let twice = digits.rem << 1u64;
+        let round_up = twice > digits.den || (twice == digits.den && last_odd);
+
+        let int = match round_up && last_below_nine.is_none() {
+            true => int + Uint::ONE,
+            false => int,
+        };
+        let mut int_buf = DigitBuf::new();
+        let int = int.encode_decimal(&mut int_buf);
+        let len = int.len() + if precision > 0 { precision + 1 } else { 0 };
+
+        let post = write_pre_padding(f, self.sign == Sign::Pos, "", len)?;
+        f.write_str(int)?;
+        if precision > 0 {
+            f.write_char('.')?;
+        }
+        let mut digits = FractionDigits::new(rem, self.den);
+        for i in 0..precision {
+            let digit = match (round_up, last_below_nine) {
+                (false, _) => digits.next_digit(),
+                (true, Some(j)) if i < j => digits.next_digit(),
+                (true, Some(j)) if i == j => digits.next_digit() + 1,
+                // the carry turned these digits into zeros
+                (true, _) => 0,
+            };
+            f.write_char(char::from_digit(digit, 10).unwrap())?;
+        }
+        post.write(f)
+    }
+}
+
+// produces the decimal digits of `rem / den` after the point, where `rem < den`
+struct FractionDigits<const S: usize> where [(); S + 1]: {
+    rem: Uint<{ S + 1 }>,
+    den: Uint<{ S + 1 }>,
+}
+
+impl<const S: usize> FractionDigits<S> where [(); S + 1]: {
+    fn new(rem: Uint<S>, den: Uint<S>) -> Self {
+        FractionDigits { rem: rem.resized(), den: den.resized() }
+    }
+
+    fn next_digit(&mut self) -> u32 {
+        // rem < den, so the digit is at most 9 and can be found by subtraction
+        self.rem *= 10u32;
+        let mut digit = 0;
+        while self.rem >= self.den {
+            self.rem -= self.den;
+            digit += 1;
+        }
+        digit
     }
 }
 
@@ -58,4 +143,43 @@ macro_rules! impl_signed_from {
     };
 }
 
-impl_signed_from!(i8, i16, i32, i64, i128);
\ No newline at end of file
+impl_signed_from!(i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use crate::rational::tests::rational;
+    use crate::Sign;
+
+    #[test]
+    fn display_fraction() {
+        assert_eq!(format!("{}", rational(Sign::Pos, 3, 4)), "3/4");
+        assert_eq!(format!("{}", rational(Sign::Neg, 3, 4)), "-3/4");
+        assert_eq!(format!("{:>6}", rational(Sign::Pos, 3, 4)), "   3/4");
+        assert_eq!(format!("{:_<6}", rational(Sign::Neg, 3, 4)), "-3/4__");
+        assert_eq!(format!("{:+}", rational(Sign::Pos, 3, 4)), "+3/4");
+        assert_eq!(format!("{:06}", rational(Sign::Neg, 3, 4)), "-003/4");
+    }
+
+    #[test]
+    fn display_decimal() {
+        assert_eq!(format!("{:.5}", rational(Sign::Pos, 1, 3)), "0.33333");
+        assert_eq!(format!("{:.3}", rational(Sign::Pos, 2, 3)), "0.667");
+        assert_eq!(format!("{:.2}", rational(Sign::Pos, 999, 1000)), "1.00");
+        assert_eq!(format!("{:.0}", rational(Sign::Pos, 19, 2)), "10");
+        assert_eq!(format!("{:.0}", rational(Sign::Pos, 17, 2)), "8");
+        assert_eq!(format!("{:.1}", rational(Sign::Pos, 199, 20)), "10.0");
+        assert_eq!(format!("{:>8.3}", rational(Sign::Neg, 1, 3)), "  -0.333");
+        assert_eq!(format!("{:+.1}", rational(Sign::Pos, 1, 4)), "+0.2");
+        assert_eq!(format!("{:.3}", rational(Sign::Pos, 7, 1)), "7.000");
+
+        // dyadic fractions are exact as floats, whose formatting rounds half to even as well
+        for _ in 0..1_000 {
+            let num = rand::random::<u32>();
+            let shift = rand::random::<u32>() % 32;
+            let precision = rand::random::<usize>() % 40;
+            let r = rational(Sign::Neg, num, 1 << shift);
+            let float = -(num as f64) / (1u64 << shift) as f64;
+            assert_eq!(format!("{:.*}", precision, r), format!("{:.*}", precision, float));
+        }
+    }
+}
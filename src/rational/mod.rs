@@ -1,11 +1,22 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
-use crate::{Uint, Sign, Sign::*};
+use crate::{Sign, Sign::*, Uint};
 
 mod add;
+#[cfg(any(test, feature = "num-bigint"))]
+mod bigint;
 mod compat;
 mod convert;
 mod mul;
+#[cfg(feature = "num-traits")]
+mod num;
+mod parse;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serde;
+
+pub use parse::ParseRationalError;
 
 #[derive(Copy, Clone)]
 pub struct Rational<const S: usize> {
@@ -30,8 +41,17 @@ where
         if gcd == Uint::ZERO {
             return self;
         }
-        self.num /= gcd;
-        self.den /= gcd;
+        // most of the time, the gcd is small enough for the faster division by a single digit
+        match u32::try_from(gcd) {
+            Ok(d) => {
+                self.num.div_rem_u32_assign(d);
+                self.den.div_rem_u32_assign(d);
+            }
+            Err(_) => {
+                self.num /= gcd;
+                self.den /= gcd;
+            }
+        }
         self
     }
 
@@ -73,12 +93,14 @@ where
     [(); 2 * S + 1]:,
 {
     fn eq(&self, other: &Self) -> bool {
+        if self.num == Uint::ZERO || other.num == Uint::ZERO {
+            // zero may have either sign
+            return self.num == other.num;
+        }
         if self.sign != other.sign {
             return false;
         }
-        let mut a = self.resized::<{ 2 * S }>();
-        let mut b = other.resized::<{ 2 * S }>();
-        to_same_denominator(&mut a, &mut b);
+        let (a, b) = to_same_denominator(*self, *other);
         a.num == b.num
     }
 }
@@ -88,14 +110,15 @@ where
     [(); S + 1]:,
     [(); 2 * S + 1]:,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        if self.num == Uint::ZERO && other.num == Uint::ZERO {
+            return Some(Ordering::Equal);
+        }
         match self.sign.cmp(&other.sign) {
             Ordering::Equal => {}
             sign => return Some(sign),
         };
-        let mut a = self.resized::<{ 2 * S }>();
-        let mut b = other.resized::<{ 2 * S }>();
-        to_same_denominator(&mut a, &mut b);
+        let (a, b) = to_same_denominator(*self, *other);
 
         if self.sign == Neg {
             b.num.partial_cmp(&a.num)
@@ -105,18 +128,39 @@ where
     }
 }
 
-fn to_same_denominator<const S: usize>(a: &mut Rational<S>, b: &mut Rational<S>)
+// brings `a` and `b` to the same denominator.
+// the products take up to 2 * S digits, so the result is returned in that size.
+fn to_same_denominator<const S: usize>(
+    a: Rational<S>,
+    b: Rational<S>,
+) -> (Rational<{ 2 * S }>, Rational<{ 2 * S }>)
 where
     [(); S + 1]:,
+    [(); 2 * S + 1]:,
 {
     if a.den == b.den {
-        return;
+        return (a.resized(), b.resized());
     }
 
-    a.num *= b.den;
-    b.num *= a.den;
+    let den = a.den.full_mul(b.den);
+    (
+        Rational::new(a.sign, a.num.full_mul(b.den), den),
+        Rational::new(b.sign, b.num.full_mul(a.den), den),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+    use crate::{Sign, Uint};
 
-    let den = a.den * b.den;
-    a.den = den;
-    b.den = den;
+    // builds `num / den` with the given sign, without reducing it
+    pub(super) fn rational(sign: Sign, num: u32, den: u32) -> Rational<2> {
+        Rational::new(sign, Uint::from_u32(num), Uint::from_u32(den))
+    }
+
+    // unlike `==`, this also checks that the fractions have the same representation
+    pub(super) fn assert_same<const S: usize>(a: Rational<S>, b: Rational<S>) {
+        assert_eq!((a.sign, a.num, a.den), (b.sign, b.num, b.den));
+    }
 }
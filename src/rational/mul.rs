@@ -1,6 +1,7 @@
-use std::ops::{Div, Mul};
+use core::ops::{Div, Mul, Rem};
 
-use crate::Rational;
+use crate::rational::to_same_denominator;
+use crate::{Rational, Sign, Uint};
 
 impl<const S: usize> Mul for Rational<S>
 where
@@ -11,13 +12,10 @@ where
 
     fn mul(self, rhs: Self) -> Self::Output {
         // this might take S*2 space
-        let lhs = self.resized::<{ 2* S }>();
-        let rhs = rhs.resized::<{ 2* S }>();
-
-        let num = lhs.num * rhs.num;
-        let den = lhs.den * rhs.den;
+        let num = self.num.full_mul::<{ 2 * S }>(rhs.num);
+        let den = self.den.full_mul::<{ 2 * S }>(rhs.den);
         Rational {
-            sign: lhs.sign * rhs.sign,
+            sign: self.sign * rhs.sign,
             num,
             den,
         }
@@ -38,3 +36,60 @@ where
         self * rhs.recip()
     }
 }
+
+impl<const S: usize> Rem for Rational<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    type Output = Self;
+
+    /// Calculates the remainder of the truncated division `self / rhs`, which has the sign of
+    /// `self`, like the remainder of primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self::Output {
+        // a/d % b/d = (a % b) / d
+        let (mut a, b) = to_same_denominator(self, rhs);
+        a.num %= b.num;
+        if a.num == Uint::ZERO {
+            a.sign = Sign::Pos;
+        }
+        a.reduced().resized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::BigRational;
+
+    use crate::rational::tests::rational;
+    use crate::{Rational, Sign, Uint};
+
+    #[test]
+    fn rem_against_num_rational() {
+        for _ in 0..500 {
+            let a = Rational::<8>::new(
+                Sign::Pos,
+                Uint::from_u128(rand::random()),
+                Uint::from_u128(rand::random::<u128>() | 1),
+            );
+            let b = Rational::new(
+                Sign::Neg,
+                Uint::from_u64(rand::random()),
+                Uint::from_u128(rand::random::<u128>() | 1),
+            );
+            for (a, b) in [(a, b), (-a, b), (a, -b), (b, a)] {
+                let expected = BigRational::from(a) % BigRational::from(b);
+                assert_eq!(BigRational::from(a % b), expected, "{} % {}", a, b);
+            }
+        }
+
+        let a = rational(Sign::Neg, 3, 2);
+        assert_eq!(a % Rational::ONE, rational(Sign::Neg, 1, 2));
+        assert_eq!(a % a, Rational::ZERO);
+        assert_eq!((a % a).sign, Sign::Pos);
+    }
+}
@@ -0,0 +1,173 @@
+use num_traits::{Inv, Num, One, Signed, Zero};
+
+use crate::{Sign, Uint};
+
+use super::{ParseRationalError, Rational};
+
+impl<const S: usize> Zero for Rational<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == Uint::ZERO
+    }
+}
+
+impl<const S: usize> One for Rational<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const S: usize> Num for Rational<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    type FromStrRadixErr = ParseRationalError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        Rational::from_str_radix(src, radix)
+    }
+}
+
+impl<const S: usize> Signed for Rational<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    fn abs(&self) -> Self {
+        Rational {
+            sign: Sign::Pos,
+            ..*self
+        }
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        if self.is_zero() {
+            Self::ZERO
+        } else {
+            Rational {
+                sign: self.sign,
+                ..Self::ONE
+            }
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.sign == Sign::Pos && !self.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.sign == Sign::Neg && !self.is_zero()
+    }
+}
+
+impl<const S: usize> Inv for Rational<S>
+where
+    [(); S + 1]:,
+{
+    type Output = Self;
+
+    /// Returns `1 / self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero.
+    fn inv(self) -> Self {
+        self.recip()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::BigRational;
+    use num_traits::{Inv, Num, One, Signed, Zero};
+
+    use crate::rational::tests::rational;
+    use crate::{Rational, Sign};
+
+    #[test]
+    fn generic_ops() {
+        // only uses the traits, like generic code would
+        fn horner<T: Num + Clone>(coefficients: &[T], x: T) -> T {
+            coefficients
+                .iter()
+                .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+        }
+
+        let coefficients = [
+            rational(Sign::Pos, 1, 2),
+            rational(Sign::Neg, 3, 1),
+            rational(Sign::Pos, 0, 1),
+            rational(Sign::Neg, 5, 7),
+        ];
+        let x = rational(Sign::Neg, 2, 3);
+        let expected = horner(&coefficients.map(BigRational::from), BigRational::from(x));
+        assert_eq!(BigRational::from(horner(&coefficients, x)), expected);
+
+        assert!(Rational::<2>::zero().is_zero());
+        assert!(Rational::<2>::one().is_one());
+        assert_eq!(
+            <Rational<2> as Num>::from_str_radix("-a/c", 16).unwrap(),
+            rational(Sign::Neg, 5, 6)
+        );
+        assert!(<Rational<2> as Num>::from_str_radix("1/0", 10).is_err());
+    }
+
+    #[test]
+    fn signed() {
+        let a = rational(Sign::Neg, 3, 4);
+        let b = rational(Sign::Pos, 1, 4);
+        let zero = Rational::<2>::ZERO;
+
+        assert_eq!(a.abs(), rational(Sign::Pos, 3, 4));
+        assert_eq!(b.abs(), b);
+        assert_eq!(a.signum(), -Rational::ONE);
+        assert_eq!(b.signum(), Rational::ONE);
+        assert_eq!(zero.signum(), zero);
+        assert!(a.is_negative() && !a.is_positive());
+        assert!(b.is_positive() && !b.is_negative());
+        assert!(!zero.is_positive() && !zero.is_negative());
+        assert_eq!(a.abs_sub(&b), zero);
+        assert_eq!(b.abs_sub(&a), Rational::ONE);
+        assert_eq!(-zero, zero);
+        assert_eq!(-(-a), a);
+        assert_eq!(-a, a.abs());
+    }
+
+    #[test]
+    fn inv() {
+        assert_eq!(rational(Sign::Neg, 3, 4).inv(), rational(Sign::Neg, 4, 3));
+        assert_eq!(Rational::<2>::ONE.inv(), Rational::ONE);
+    }
+
+    #[test]
+    fn complex() {
+        // `num_complex::Complex` works with any `Num`
+        let z = num_complex::Complex::new(rational(Sign::Pos, 1, 2), rational(Sign::Neg, 1, 3));
+        let one = num_complex::Complex::new(Rational::ONE, Rational::ZERO);
+        assert_eq!(z * z.inv(), one);
+        assert_eq!(
+            z * z,
+            num_complex::Complex::new(rational(Sign::Pos, 5, 36), rational(Sign::Neg, 1, 3))
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{ParseUintError, Sign, Uint};
+
+use super::Rational;
+
+/// An error which can be returned when parsing a [`Rational`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ParseRationalError {
+    /// The numerator or the denominator is not a valid [`Uint`].
+    Invalid(ParseUintError),
+    /// The denominator is zero.
+    ZeroDenominator,
+}
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRationalError::Invalid(e) => fmt::Display::fmt(e, f),
+            ParseRationalError::ZeroDenominator => f.write_str("denominator is zero"),
+        }
+    }
+}
+
+impl From<ParseUintError> for ParseRationalError {
+    fn from(e: ParseUintError) -> Self {
+        ParseRationalError::Invalid(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRationalError {}
+
+impl<const S: usize> Rational<S>
+where
+    [(); S + 1]:,
+{
+    /// Parses a fraction like `-3/4` in a given base. Without a `/`, the denominator is 1.
+    ///
+    /// The numerator and the denominator are parsed like [`Uint::from_str_radix`]. The fraction is
+    /// not reduced.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseRationalError> {
+        Self::parse_with(src, |v| Uint::from_str_radix(v, radix))
+    }
+
+    fn parse_with(
+        src: &str,
+        parse: impl Fn(&str) -> Result<Uint<S>, ParseUintError>,
+    ) -> Result<Self, ParseRationalError> {
+        let (sign, src) = match src.strip_prefix('-') {
+            Some(src) => (Sign::Neg, src),
+            None => (Sign::Pos, src),
+        };
+        let (num, den) = match src.split_once('/') {
+            Some((num, den)) => (parse(num)?, parse(den)?),
+            None => (parse(src)?, Uint::ONE),
+        };
+        if den == Uint::ZERO {
+            return Err(ParseRationalError::ZeroDenominator);
+        }
+        // there is no negative zero
+        let sign = if num == Uint::ZERO { Sign::Pos } else { sign };
+        Ok(Rational::new(sign, num, den))
+    }
+}
+
+impl<const S: usize> FromStr for Rational<S>
+where
+    [(); S + 1]:,
+{
+    type Err = ParseRationalError;
+
+    /// Parses a fraction like `-3/4`. Without a `/`, the denominator is 1.
+    /// The numerator and the denominator are parsed like [`Uint::from_str`], so they may use the
+    /// prefixes `0x`, `0o` and `0b`. The fraction is not reduced.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(src, Uint::from_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseRationalError;
+    use crate::rational::tests::{assert_same, rational};
+    use crate::{ParseUintError, Rational, Sign};
+
+    #[test]
+    fn from_str() {
+        assert_same("-3/4".parse().unwrap(), rational(Sign::Neg, 3, 4));
+        assert_same("6/4".parse().unwrap(), rational(Sign::Pos, 6, 4));
+        assert_same("0x10/0b11".parse().unwrap(), rational(Sign::Pos, 16, 3));
+        assert_same("-7".parse().unwrap(), rational(Sign::Neg, 7, 1));
+        assert_same("-0/5".parse().unwrap(), rational(Sign::Pos, 0, 5));
+
+        let parse = |s: &str| s.parse::<Rational<2>>().map(|_| ());
+        assert_eq!(parse("1/0"), Err(ParseRationalError::ZeroDenominator));
+        assert_eq!(parse("1/"), Err(ParseUintError::Empty.into()));
+        assert_eq!(parse(""), Err(ParseUintError::Empty.into()));
+        assert_eq!(parse("--1/2"), Err(ParseUintError::InvalidDigit.into()));
+        assert_eq!(parse("1/-2"), Err(ParseUintError::InvalidDigit.into()));
+        assert_eq!(parse("1/2/3"), Err(ParseUintError::InvalidDigit.into()));
+        assert_eq!(
+            "1/4294967296".parse::<Rational<1>>().map(|_| ()),
+            Err(ParseUintError::Overflow.into())
+        );
+    }
+
+    #[test]
+    fn from_str_radix() {
+        assert_same(
+            Rational::from_str_radix("-ff/10", 16).unwrap(),
+            rational(Sign::Neg, 255, 16),
+        );
+        assert_same(
+            Rational::from_str_radix("z", 36).unwrap(),
+            rational(Sign::Pos, 35, 1),
+        );
+        assert_eq!(
+            Rational::<2>::from_str_radix("0x1/2", 16).map(|_| ()),
+            Err(ParseUintError::InvalidDigit.into())
+        );
+    }
+}
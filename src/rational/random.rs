@@ -0,0 +1,57 @@
+use rand::Rng;
+
+use crate::{Sign, Uint};
+
+use super::Rational;
+
+impl<const S: usize> Rational<S>
+where
+    [(); S + 1]:,
+{
+    /// Generates a random fraction `±num / den` with `num <= max_num` and `1 <= den <= max_den`.
+    /// The sign, numerator and denominator are chosen uniformly, and the result is reduced.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_den` is zero
+    pub fn random_bounded<R: Rng + ?Sized>(
+        max_num: Uint<S>,
+        max_den: Uint<S>,
+        rng: &mut R,
+    ) -> Self {
+        assert_ne!(max_den, Uint::ZERO, "the denominator must be at least one");
+        let num = rng.gen_range(Uint::ZERO..=max_num);
+        let den = rng.gen_range(Uint::ONE..=max_den);
+        let sign = match num != Uint::ZERO && rng.gen() {
+            true => Sign::Neg,
+            false => Sign::Pos,
+        };
+        Rational::new(sign, num, den).reduced()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rational, Sign, Uint};
+
+    #[test]
+    fn random_bounded() {
+        let mut rng = rand::thread_rng();
+        let (max_num, max_den) = (Uint::<4>::from_u32(1000), Uint::<4>::from_u32(30));
+        let mut signs = (false, false);
+        for _ in 0..1_000 {
+            let v = Rational::random_bounded(max_num, max_den, &mut rng);
+            assert!(v.num <= max_num);
+            assert!(Uint::ONE <= v.den && v.den <= max_den);
+            assert_eq!(v.num.gcd_euclidean(v.den), Uint::ONE);
+            match v.sign {
+                Sign::Pos => signs.0 = true,
+                Sign::Neg => signs.1 = true,
+            }
+        }
+        assert_eq!(signs, (true, true));
+
+        let zero = Rational::random_bounded(Uint::<4>::ZERO, Uint::ONE, &mut rng);
+        assert!(zero.num == Uint::ZERO && zero.sign == Sign::Pos);
+    }
+}
@@ -0,0 +1,141 @@
+//! Human-readable formats store a `Rational` as a string like `-3/4`. Other formats store the sign,
+//! numerator and denominator one after another, each in the encoding of its own type.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::Uint;
+
+use super::Rational;
+
+impl<const S: usize> Serialize for Rational<S>
+where
+    [(); S + 1]:,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&self.sign)?;
+        tuple.serialize_element(&self.num)?;
+        tuple.serialize_element(&self.den)?;
+        tuple.end()
+    }
+}
+
+impl<'de, const S: usize> Deserialize<'de> for Rational<S>
+where
+    [(); S + 1]:,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor)
+        } else {
+            deserializer.deserialize_tuple(3, PartsVisitor)
+        }
+    }
+}
+
+struct StrVisitor<const S: usize>;
+
+impl<const S: usize> Visitor<'_> for StrVisitor<S>
+where
+    [(); S + 1]:,
+{
+    type Value = Rational<S>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a fraction like \"-3/4\", or an integer")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+struct PartsVisitor<const S: usize>;
+
+impl<'de, const S: usize> Visitor<'de> for PartsVisitor<S>
+where
+    [(); S + 1]:,
+{
+    type Value = Rational<S>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sign, a numerator and a non-zero denominator")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let sign = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let num = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let den: Uint<S> = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        if den == Uint::ZERO {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Other("a zero denominator"),
+                &self,
+            ));
+        }
+        Ok(Rational::new(sign, num, den))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rational::tests::assert_same;
+    use crate::{Rational, Sign, Uint};
+
+    #[test]
+    fn json() {
+        let v = Rational::<2>::new(Sign::Neg, Uint::from_u32(3), Uint::from_u32(4));
+        assert_eq!(serde_json::to_string(&v).unwrap(), "\"-3/4\"");
+        assert_same(serde_json::from_str("\"-3/4\"").unwrap(), v);
+        assert_same(
+            serde_json::from_str("\"0x10/3\"").unwrap(),
+            Rational::<2>::new(Sign::Pos, Uint::from_u32(16), Uint::from_u32(3)),
+        );
+        assert_same(
+            serde_json::from_str("\"-7\"").unwrap(),
+            Rational::<2>::new(Sign::Neg, Uint::from_u32(7), Uint::ONE),
+        );
+
+        assert!(serde_json::from_str::<Rational<2>>("\"1/0\"").is_err());
+        assert!(serde_json::from_str::<Rational<2>>("\"1/\"").is_err());
+        assert!(serde_json::from_str::<Rational<2>>("\"--1/2\"").is_err());
+        assert!(serde_json::from_str::<Rational<2>>("\"1/-2\"").is_err());
+        assert!(serde_json::from_str::<Rational<1>>("\"1/4294967296\"").is_err());
+    }
+
+    #[test]
+    fn binary() {
+        let v = Rational::<2>::new(Sign::Neg, Uint::from_u32(3), Uint::from_u32(4));
+        let bytes = bincode::serialize(&v).unwrap();
+        // the variant index of the sign, followed by the digits
+        assert_eq!(bytes.len(), 4 + 2 * 2 * 4);
+        assert_same(bincode::deserialize(&bytes).unwrap(), v);
+
+        let zero_den = bincode::serialize(&(Sign::Pos, Uint::<2>::ONE, Uint::<2>::ZERO)).unwrap();
+        assert!(bincode::deserialize::<Rational<2>>(&zero_den).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..100 {
+            let sign = if rand::random() { Sign::Pos } else { Sign::Neg };
+            let den = Uint::<4>::new(rand::random()) | Uint::ONE;
+            let v = Rational::<4>::new(sign, Uint::new(rand::random()), den);
+            let json = serde_json::to_string(&v).unwrap();
+            assert_same(serde_json::from_str(&json).unwrap(), v);
+            let bytes = bincode::serialize(&v).unwrap();
+            assert_same(bincode::deserialize(&bytes).unwrap(), v);
+        }
+    }
+}
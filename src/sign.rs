@@ -1,6 +1,7 @@
-use std::ops::Mul;
+use core::ops::Mul;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i8)]
 pub enum Sign {
     Pos = 1,
@@ -20,13 +21,13 @@ impl Mul for Sign {
 }
 
 impl PartialOrd for Sign {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         (*self as i8).partial_cmp(&(*other as i8))
     }
 }
 
 impl Ord for Sign {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         (*self as i8).cmp(&(*other as i8))
     }
 }
\ No newline at end of file
@@ -1,10 +1,12 @@
 use crate::uint::Uint;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
-impl<const SIZE: usize> Add for Uint<SIZE> {
-    type Output = Self;
-
-    fn add(mut self, rhs: Self) -> Self::Output {
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Calculates `self + rhs`.
+    ///
+    /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_add(mut self, rhs: Self) -> (Self, bool) {
         let mut carry = false;
         for i in (0..SIZE).rev() {
             let l = &mut self.digits[i];
@@ -12,8 +14,76 @@ impl<const SIZE: usize> Add for Uint<SIZE> {
             carry = c;
             *l = res;
         }
+        (self, carry)
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) addition. Computes `self + rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// Returns a tuple of the subtraction along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_sub(mut self, rhs: Self) -> (Self, bool) {
+        let mut borrow = false;
+        for i in (0..SIZE).rev() {
+            let l = &mut self.digits[i];
+            let (res, b) = l.borrowing_sub(rhs.digits[i], borrow);
+            borrow = b;
+            *l = res;
+        }
+        (self, borrow)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) subtraction. Computes `self - rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Negates `self` in an overflowing fashion.
+    ///
+    /// Returns `!self + 1` using wrapping operations to return the value that represents the
+    /// negation of this unsigned value. The boolean is `true` unless `self` is zero.
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        Self::ZERO.overflowing_sub(self)
+    }
+
+    /// Checked negation. Computes `-self`, returning `None` unless `self == 0`.
+    pub fn checked_neg(self) -> Option<Self> {
+        Self::ZERO.checked_sub(self)
+    }
+
+    /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary of the type.
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO.wrapping_sub(self)
+    }
+}
+
+impl<const SIZE: usize> Add for Uint<SIZE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (res, carry) = self.overflowing_add(rhs);
         debug_assert!(!carry, "attempt to add with overflow");
-        self
+        res
     }
 }
 
@@ -26,16 +96,10 @@ impl<const SIZE: usize> AddAssign for Uint<SIZE> {
 impl<const SIZE: usize> Sub for Uint<SIZE> {
     type Output = Self;
 
-    fn sub(mut self, rhs: Self) -> Self::Output {
-        let mut borrow = false;
-        for i in (0..SIZE).rev() {
-            let l = &mut self.digits[i];
-            let (res, b) = l.borrowing_sub(rhs.digits[i], borrow);
-            borrow = b;
-            *l = res;
-        }
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (res, borrow) = self.overflowing_sub(rhs);
         debug_assert!(!borrow, "attempt to subtract with overflow");
-        self
+        res
     }
 }
 
@@ -61,4 +125,35 @@ mod tests {
             digits: [0, 0, u32::MAX]
         })
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn overflowing_against_u128() {
+        fn check(a: u128, b: u128) {
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+
+            let (res, o) = my_a.overflowing_add(my_b);
+            assert_eq!((res.to_u128().unwrap(), o), a.overflowing_add(b));
+            assert_eq!(my_a.checked_add(my_b).map(|r| r.to_u128().unwrap()), a.checked_add(b));
+            assert_eq!(my_a.wrapping_add(my_b).to_u128().unwrap(), a.wrapping_add(b));
+
+            let (res, o) = my_a.overflowing_sub(my_b);
+            assert_eq!((res.to_u128().unwrap(), o), a.overflowing_sub(b));
+            assert_eq!(my_a.checked_sub(my_b).map(|r| r.to_u128().unwrap()), a.checked_sub(b));
+            assert_eq!(my_a.wrapping_sub(my_b).to_u128().unwrap(), a.wrapping_sub(b));
+
+            let (res, o) = my_a.overflowing_neg();
+            assert_eq!((res.to_u128().unwrap(), o), a.overflowing_neg());
+            assert_eq!(my_a.checked_neg().map(|r| r.to_u128().unwrap()), a.checked_neg());
+        }
+
+        check(0, 0);
+        check(u128::MAX, 1);
+        check(1, u128::MAX);
+        check(u128::MAX, u128::MAX);
+        check(u64::MAX as u128, 1);
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(rand::random::<u64>() as u128, rand::random());
+        }
+    }
+}
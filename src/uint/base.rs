@@ -1,49 +1,111 @@
-use core::{fmt, iter};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::iter;
 
+use super::radix;
 use super::Array2N;
+use super::ParseUintError;
 use super::Uint;
 
-impl<const SIZE: usize> Uint<SIZE> where [(); SIZE + 1]: {
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Creates a number from its digits in the given base.
+    /// This is the counterpart to [`Uint::to_base_le`] - digits are expected in little-endian
+    /// order, starting with the least significant digit.
+    ///
+    /// An empty iterator yields zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `base` is smaller than 2
+    pub fn from_base_le(
+        digits: impl IntoIterator<Item = u32>,
+        base: u32,
+    ) -> Result<Self, ParseUintError> {
+        assert!(base >= 2, "base must be at least 2");
+
+        let mut result = Self::ZERO;
+        // `base^i` - becomes `None` once it doesn't fit anymore.
+        // from then on, only zeros are allowed.
+        let mut power = Some(Self::ONE);
+        for digit in digits {
+            if digit >= base {
+                return Err(ParseUintError::InvalidDigit);
+            }
+            if digit != 0 {
+                result = power
+                    .and_then(|p| p.checked_mul_u32(digit))
+                    .and_then(|d| result.checked_add(d))
+                    .ok_or(ParseUintError::Overflow)?;
+            }
+            power = power.and_then(|p| p.checked_mul_u32(base));
+        }
+        Ok(result)
+    }
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
     /// Converts this number to the given base.  
     /// Each element of the returned iterator represents one digit in the given base.
     /// Digits are yielded in little-endian order, starting with the least significant digit.
     ///
     /// The iterator is guaranteed to always yield at least one digit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `base` is smaller than 2
     pub fn to_base_le(mut self, base: u32) -> impl Iterator<Item = u32> {
-        let base = Uint::from_u32(base);
+        assert!(base >= 2, "base must be at least 2");
         let mut i = 0;
 
         iter::from_fn(move || {
             if i != 0 && self == Self::ZERO {
                 return None;
             }
-            let (q, r) = self.div_rem(base);
-            self = q;
             i += 1;
-
-            // since we divided by base < 2^32, r < base < 2^32. So the remainder fits in one u32.
-            Some(r.digits[SIZE - 1])
+            Some(self.div_rem_u32_assign(base))
         })
     }
 
-    // can this be generalized to work with any basis?
-    // for that to work, some math has to be done (and a lot of const trickery).
-    // definitely something to look into - the more generic `to_string_radix` needs to allocate.
+    /// Converts this number to base 10^9, yielding the digits in big-endian order, starting with
+    /// the most significant digit.
+    ///
+    /// The iterator is guaranteed to always yield at least one digit.
     pub fn to_base_10_be(self) -> impl Iterator<Item = u32> {
         // digits in base (10^9). Since (10^9)^(SIZE * 2) > (2^32)^SIZE, everything fits in here
         let mut digits = Array2N::<SIZE, u32>::new(0);
-        let mut len = 0;
-        for (i, digit) in self.to_base_le(1_000_000_000).enumerate() {
-            digits[i] = digit;
-            len = i + 1;
-        }
+        let len = self.write_base_1e9_le(&mut digits);
         debug_assert!(len > 0);
 
         (0..len).map(move |i| digits[i]).rev()
     }
 
-    pub fn to_string_radix(self, radix: u32) -> String {
+    // writes the digits of this number in base 10^9 in little-endian order to `out`,
+    // returning how many digits were written.
+    pub(super) fn write_base_1e9_le(&self, out: &mut Array2N<SIZE, u32>) -> usize {
+        if self.significant_digits() < radix::SPLIT_THRESHOLD {
+            let mut x = *self;
+            let mut len = 0;
+            loop {
+                out[len] = x.div_rem_u32_assign(radix::CHUNK);
+                len += 1;
+                if x == Self::ZERO {
+                    return len;
+                }
+            }
+        }
+
+        let mut scratch = Array2N::<SIZE, u32>::new(0);
+        let mut powers = Array2N::<SIZE, u32>::new(0);
+        radix::to_base_1e9(
+            &self.to_limbs_le(),
+            out.as_mut_slice(),
+            scratch.as_mut_slice(),
+            powers.as_mut_slice(),
+        )
+    }
 
+    #[cfg(feature = "alloc")]
+    pub fn to_string_radix(self, radix: u32) -> String {
         self.to_base_le(radix)
             .map(|c| char::from_digit(c, radix).unwrap())
             .collect::<Vec<char>>()
@@ -53,55 +115,90 @@ impl<const SIZE: usize> Uint<SIZE> where [(); SIZE + 1]: {
     }
 }
 
-impl <const SIZE: usize> fmt::Display for Uint<SIZE> where [(); SIZE + 1]: {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut digits = self.to_base_10_be();
-
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
 
-        // print the MS digit without leading 0's
-        write!(f, "{}", digits.next().unwrap())?;
+    use crate::uint::radix::SPLIT_THRESHOLD;
+    use crate::{ParseUintError, Uint};
 
-        for digit in digits {
-            write!(f, "{:09}", digit)?;
+    #[test]
+    fn from_base_le() {
+        let v = Uint::<4>::from_u128(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+        for base in [2, 3, 10, 16, 1_000_000_000, u32::MAX] {
+            assert_eq!(Uint::from_base_le(v.to_base_le(base), base), Ok(v));
         }
-
-        Ok(())
+        assert_eq!(Uint::<1>::from_base_le([], 10), Ok(Uint::ZERO));
+        assert_eq!(Uint::<1>::from_base_le([1, 0, 0, 0], 1 << 16), Ok(Uint::ONE));
+        assert_eq!(Uint::<1>::from_base_le([1, 10], 10), Err(ParseUintError::InvalidDigit));
+        assert_eq!(Uint::<1>::from_base_le([0, 0, 1], 1 << 16), Err(ParseUintError::Overflow));
     }
-}
 
-impl <const SIZE: usize> fmt::Binary for Uint<SIZE> where [(); SIZE + 1]: {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_string_radix(2))
+    #[test]
+    fn to_base_le_against_num_bigint() {
+        for base in [2, 3, 7, 10, 16, 1_000_000_000, u32::MAX] {
+            let v = Uint::<8>::new(rand::random());
+            let digits: Vec<u32> = v.to_base_le(base).collect();
+            assert!(digits.iter().all(|d| *d < base));
+            assert_ne!(digits.last(), Some(&0));
+            let recombined = digits
+                .iter()
+                .rev()
+                .fold(BigUint::from(0u32), |acc, d| acc * base + *d);
+            assert_eq!(v, recombined);
+        }
     }
-}
 
-impl <const SIZE: usize> fmt::LowerHex for Uint<SIZE> where [(); SIZE + 1]: {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_string_radix(16))
+    fn check_base_10<const S: usize>(v: Uint<S>) {
+        let digits: Vec<u32> = v.to_base_10_be().collect();
+        let expected = BigUint::from(v).to_str_radix(10);
+        let mut actual = digits[0].to_string();
+        for digit in &digits[1..] {
+            actual += &format!("{:09}", digit);
+        }
+        assert_eq!(actual, expected);
     }
-}
 
-impl <const SIZE: usize> fmt::UpperHex for Uint<SIZE> where [(); SIZE + 1]: {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut hex = self.to_string_radix(16);
-        hex.make_ascii_uppercase();
-        write!(f, "{}", hex)
+    #[test]
+    fn to_base_10_against_num_bigint() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            // numbers around the threshold for splitting, and far above it
+            for len in [1, SPLIT_THRESHOLD - 1, SPLIT_THRESHOLD, SPLIT_THRESHOLD + 1, 300, 600] {
+                let mut digits = [0; 600];
+                rng.fill(&mut digits[600 - len..]);
+                check_base_10(Uint::<600>::new(digits));
+            }
+        }
+        check_base_10(Uint::<600>::ZERO);
+        check_base_10(Uint::<600>::MAX);
+        check_base_10(Uint::<64>::MAX);
     }
-}
 
-impl <const SIZE: usize> fmt::Octal for Uint<SIZE> where [(); SIZE + 1]: {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_string_radix(8))
+    #[test]
+    fn to_base_10_powers() {
+        // the powers of 10^9 which are used to split large numbers, and their neighbours
+        let chunk = Uint::<300>::from_u32(1_000_000_000);
+        let mut power = chunk;
+        while let Some(square) = power.checked_mul(power) {
+            power = square;
+            check_base_10(power);
+            check_base_10(power - Uint::ONE);
+            check_base_10(power + Uint::ONE);
+            check_base_10(power * chunk);
+        }
+        // runs of zeros and nines in the output
+        check_base_10(Uint::<300>::from_u32(10).pow(2000) - Uint::ONE);
+        check_base_10(Uint::<300>::from_u32(10).pow(2000) + Uint::from_u32(7));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::Uint;
 
     #[test]
-    fn big() {
+    #[cfg(feature = "alloc")]
+    fn to_string_radix() {
         let v = Uint::<100>::new([u32::MAX; 100]);
         assert_eq!(v.to_string_radix(16), "ff".repeat(4 * 100));
+        assert_eq!(Uint::<1>::ZERO.to_string_radix(7), "0");
+        assert_eq!(Uint::<1>::from_u32(35).to_string_radix(36), "z");
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,110 @@
+//! Conversions between `Uint` and the arbitrary precision integers of `num-bigint`.
+
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::uint::Uint;
+use crate::TryFromIntError;
+
+impl<const SIZE: usize> From<Uint<SIZE>> for BigUint {
+    fn from(v: Uint<SIZE>) -> Self {
+        BigUint::new(v.digits_be().iter().copied().rev().collect())
+    }
+}
+
+impl<const SIZE: usize> From<Uint<SIZE>> for BigInt {
+    fn from(v: Uint<SIZE>) -> Self {
+        BigInt::from(BigUint::from(v))
+    }
+}
+
+impl<const SIZE: usize> TryFrom<&BigUint> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    /// Converts a `BigUint`, failing if it does not fit into `SIZE` digits.
+    fn try_from(v: &BigUint) -> Result<Self, Self::Error> {
+        let mut result = Self::ZERO;
+        // `iter_u32_digits` starts with the least significant digit
+        for (i, digit) in v.iter_u32_digits().enumerate() {
+            if i == SIZE {
+                return Err(TryFromIntError(()));
+            }
+            result.digits[SIZE - 1 - i] = digit;
+        }
+        Ok(result)
+    }
+}
+
+impl<const SIZE: usize> TryFrom<BigUint> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: BigUint) -> Result<Self, Self::Error> {
+        Self::try_from(&v)
+    }
+}
+
+impl<const SIZE: usize> TryFrom<&BigInt> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    /// Converts a `BigInt`, failing if it is negative or does not fit into `SIZE` digits.
+    fn try_from(v: &BigInt) -> Result<Self, Self::Error> {
+        if v.sign() == Sign::Minus {
+            return Err(TryFromIntError(()));
+        }
+        Self::try_from(v.magnitude())
+    }
+}
+
+impl<const SIZE: usize> TryFrom<BigInt> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: BigInt) -> Result<Self, Self::Error> {
+        Self::try_from(&v)
+    }
+}
+
+impl<const SIZE: usize> PartialEq<BigUint> for Uint<SIZE> {
+    fn eq(&self, other: &BigUint) -> bool {
+        Uint::try_from(other) == Ok(*self)
+    }
+}
+
+impl<const SIZE: usize> PartialEq<Uint<SIZE>> for BigUint {
+    fn eq(&self, other: &Uint<SIZE>) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, BigUint};
+
+    use crate::{TryFromIntError, Uint};
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..100 {
+            let v = Uint::<8>::new(rand::random());
+            assert_eq!(Uint::<8>::try_from(BigUint::from(v)), Ok(v));
+            assert_eq!(Uint::<8>::try_from(BigInt::from(v)), Ok(v));
+            assert_eq!(BigUint::from(v), v);
+        }
+        assert_eq!(BigUint::from(Uint::<4>::ZERO), BigUint::from(0u32));
+        assert_eq!(Uint::<4>::try_from(BigUint::from(0u32)), Ok(Uint::ZERO));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let large = BigUint::from(1u32) << 128;
+        assert_eq!(Uint::<4>::try_from(&large), Err(TryFromIntError(())));
+        assert_eq!(Uint::<5>::try_from(&large), Ok(Uint::ONE << 128u64));
+        assert_eq!(
+            Uint::<5>::try_from(BigUint::from(u128::MAX)),
+            Ok(Uint::from_u128(u128::MAX))
+        );
+        assert_eq!(
+            Uint::<4>::try_from(BigInt::from(-1)),
+            Err(TryFromIntError(()))
+        );
+        assert_ne!(Uint::<4>::MAX, large);
+    }
+}
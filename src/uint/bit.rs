@@ -0,0 +1,339 @@
+use crate::uint::Uint;
+use core::iter;
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, ShlAssign, ShrAssign,
+};
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Returns the number of leading zeros in the binary representation of `self`.
+    pub fn leading_zeros(&self) -> u32 {
+        let mut result = 0;
+        for digit in &self.digits {
+            let zeros = digit.leading_zeros();
+            result += zeros;
+            if zeros < 32 {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Returns the number of ones in the binary representation of `self`.
+    pub fn count_ones(&self) -> u32 {
+        self.digits.iter().map(|d| d.count_ones()).sum()
+    }
+
+    /// Returns the number of zeros in the binary representation of `self`.
+    pub fn count_zeros(&self) -> u32 {
+        Self::BITS - self.count_ones()
+    }
+
+    /// Returns the number of bits necessary to represent `self`, which is 0 for zero.
+    pub fn bits(&self) -> u32 {
+        Self::BITS - self.leading_zeros()
+    }
+
+    /// Returns whether the bit at index `i` is set, where `0` is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i >= BITS`
+    pub fn bit(&self, i: u32) -> bool {
+        let (digit, mask) = Self::bit_position(i);
+        self.digits[digit] & mask != 0
+    }
+
+    /// Sets the bit at index `i` to 1.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i >= BITS`
+    pub fn set_bit(&mut self, i: u32) {
+        let (digit, mask) = Self::bit_position(i);
+        self.digits[digit] |= mask;
+    }
+
+    /// Sets the bit at index `i` to 0.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i >= BITS`
+    pub fn clear_bit(&mut self, i: u32) {
+        let (digit, mask) = Self::bit_position(i);
+        self.digits[digit] &= !mask;
+    }
+
+    /// Flips the bit at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i >= BITS`
+    pub fn toggle_bit(&mut self, i: u32) {
+        let (digit, mask) = Self::bit_position(i);
+        self.digits[digit] ^= mask;
+    }
+
+    // returns the index of the digit containing bit `i`, and a mask selecting it within the digit
+    fn bit_position(i: u32) -> (usize, u32) {
+        assert!(i < Self::BITS, "bit index out of range");
+        (SIZE - 1 - (i / 32) as usize, 1 << (i % 32))
+    }
+
+    /// Returns `true` if the least significant bit is set.
+    pub fn is_odd(&self) -> bool {
+        self.digits[SIZE - 1] & 1 == 1
+    }
+
+    /// Returns `true` if the least significant bit is not set.
+    pub fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    /// Reverses the order of bits in the integer.
+    /// The least significant bit becomes the most significant bit, and so on.
+    pub fn reverse_bits(mut self) -> Self {
+        self.digits.reverse();
+        for digit in &mut self.digits {
+            *digit = digit.reverse_bits();
+        }
+        self
+    }
+
+    /// Shifts the bits to the left by `n`, wrapping the truncated bits to the end of the
+    /// resulting integer.
+    pub fn rotate_left(self, n: u32) -> Self {
+        let n = n % Self::BITS;
+        if n == 0 {
+            return self;
+        }
+        (self << n as u64) | (self >> (Self::BITS - n) as u64)
+    }
+
+    /// Shifts the bits to the right by `n`, wrapping the truncated bits to the beginning of the
+    /// resulting integer.
+    pub fn rotate_right(self, n: u32) -> Self {
+        self.rotate_left(Self::BITS - n % Self::BITS)
+    }
+
+    /// Returns an iterator over the indices of all set bits, starting with the least significant one.
+    pub fn iter_ones(self) -> impl Iterator<Item = u32> {
+        (0..SIZE).flat_map(move |i| {
+            let mut digit = self.digits[SIZE - 1 - i];
+            iter::from_fn(move || {
+                if digit == 0 {
+                    return None;
+                }
+                let zeros = digit.trailing_zeros();
+                digit &= digit - 1;
+                Some(i as u32 * 32 + zeros)
+            })
+        })
+    }
+}
+
+impl<const SIZE: usize> Not for Uint<SIZE> {
+    type Output = Self;
+
+    fn not(mut self) -> Self::Output {
+        for digit in &mut self.digits {
+            *digit = !*digit;
+        }
+        self
+    }
+}
+
+macro_rules! impl_bit_ops {
+    ($($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $tok:tt, $assign_tok:tt;)*) => {
+        $(
+            impl<const SIZE: usize> $op for Uint<SIZE> {
+                type Output = Self;
+
+                fn $op_fn(mut self, rhs: Self) -> Self::Output {
+                    self $assign_tok rhs;
+                    self
+                }
+            }
+
+            impl<const SIZE: usize> $assign for Uint<SIZE> {
+                fn $assign_fn(&mut self, rhs: Self) {
+                    for i in 0..SIZE {
+                        self.digits[i] $assign_tok rhs.digits[i];
+                    }
+                }
+            }
+
+            impl_bit_ops!(@prim $op, $op_fn, $assign, $assign_fn, $tok; u8, u16, u32, u64, u128);
+        )*
+    };
+    (@prim $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $tok:tt; $($t:ty),*) => {
+        $(
+            impl<const SIZE: usize> $op<$t> for Uint<SIZE> {
+                type Output = Self;
+
+                fn $op_fn(self, rhs: $t) -> Self::Output {
+                    self $tok Uint::truncated_from_u128(rhs as u128)
+                }
+            }
+
+            impl<const SIZE: usize> $assign<$t> for Uint<SIZE> {
+                fn $assign_fn(&mut self, rhs: $t) {
+                    *self = *self $tok Uint::truncated_from_u128(rhs as u128);
+                }
+            }
+        )*
+    };
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    // converts `v` like an `as` cast would, dropping the bits which don't fit into `SIZE` digits.
+    // this lets the bit operations take primitives which are larger than the number.
+    fn truncated_from_u128(v: u128) -> Self {
+        let mut out = Self::ZERO;
+        for i in 0..SIZE.min(4) {
+            out.digits[SIZE - 1 - i] = (v >> (32 * i)) as u32;
+        }
+        out
+    }
+}
+
+impl_bit_ops! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, &, &=;
+    BitOr, bitor, BitOrAssign, bitor_assign, |, |=;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, ^, ^=;
+}
+
+impl<const SIZE: usize> ShlAssign<u64> for Uint<SIZE> {
+    fn shl_assign(&mut self, rhs: u64) {
+        *self = *self << rhs;
+    }
+}
+
+impl<const SIZE: usize> ShrAssign<u64> for Uint<SIZE> {
+    fn shr_assign(&mut self, rhs: u64) {
+        *self = *self >> rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uint::Uint;
+
+    #[test]
+    fn against_u128() {
+        fn check(a: u128, b: u128) {
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+            assert_eq!((my_a & my_b).to_u128(), Some(a & b));
+            assert_eq!((my_a | my_b).to_u128(), Some(a | b));
+            assert_eq!((my_a ^ my_b).to_u128(), Some(a ^ b));
+            assert_eq!((!my_a).to_u128(), Some(!a));
+            assert_eq!((my_a & b as u32).to_u128(), Some(a & (b as u32 as u128)));
+            assert_eq!((my_a | b as u64).to_u128(), Some(a | (b as u64 as u128)));
+            assert_eq!((my_a ^ b).to_u128(), Some(a ^ b));
+
+            let mut c = my_a;
+            c &= my_b;
+            c |= 0xffu8;
+            c ^= my_a;
+            assert_eq!(c.to_u128(), Some(((a & b) | 0xff) ^ a));
+        }
+
+        check(0, 0);
+        check(u128::MAX, 0);
+        check(u128::MAX, u128::MAX);
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+        }
+    }
+
+    #[test]
+    fn primitives_larger_than_the_number() {
+        for _ in 0..100 {
+            let (a, b) = (rand::random::<u64>(), rand::random::<u128>());
+            let my_a = Uint::<2>::from_u64(a);
+            // the primitive is truncated to the size of the number, like with `as`
+            assert_eq!((my_a & b).to_u128(), Some((a & b as u64) as u128));
+            assert_eq!((my_a | b).to_u128(), Some((a | b as u64) as u128));
+            assert_eq!((my_a ^ b).to_u128(), Some((a ^ b as u64) as u128));
+            assert_eq!((my_a & b as u64).to_u128(), Some((a & b as u64) as u128));
+
+            let my_a = Uint::<1>::from_u32(a as u32);
+            assert_eq!(
+                (my_a & b as u64).to_u128(),
+                Some((a as u32 & b as u32) as u128)
+            );
+            assert_eq!((my_a | b).to_u128(), Some((a as u32 | b as u32) as u128));
+            let mut c = my_a;
+            c ^= b as u64;
+            c &= b;
+            assert_eq!(
+                c.to_u128(),
+                Some(((a as u32 ^ b as u32) & b as u32) as u128)
+            );
+        }
+        assert_eq!(Uint::<2>::MAX & 0xffu128, Uint::from_u32(0xff));
+        assert_eq!(Uint::<1>::ZERO | u64::MAX, Uint::MAX);
+    }
+
+    #[test]
+    fn inspection_against_u128() {
+        fn check(a: u128) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.leading_zeros(), a.leading_zeros());
+            assert_eq!(my_a.count_ones(), a.count_ones());
+            assert_eq!(my_a.count_zeros(), a.count_zeros());
+            assert_eq!(my_a.bits(), 128 - a.leading_zeros());
+            assert_eq!(my_a.is_odd(), a % 2 == 1);
+            assert_eq!(my_a.reverse_bits().to_u128(), Some(a.reverse_bits()));
+            for n in [0, 1, 31, 32, 33, 64, 127, 128, 200] {
+                assert_eq!(my_a.rotate_left(n).to_u128(), Some(a.rotate_left(n)));
+                assert_eq!(my_a.rotate_right(n).to_u128(), Some(a.rotate_right(n)));
+            }
+            let ones = (0..128).filter(|i| a & (1 << i) != 0);
+            assert!(my_a.iter_ones().eq(ones));
+        }
+
+        check(0);
+        check(1);
+        check(u128::MAX);
+        check(1 << 127);
+        for _ in 0..1_000 {
+            check(rand::random());
+            check(rand::random::<u64>() as u128);
+        }
+    }
+
+    #[test]
+    fn get_set_bits() {
+        let mut a = Uint::<3>::ZERO;
+        a.set_bit(0);
+        a.set_bit(33);
+        a.set_bit(95);
+        assert_eq!(a, Uint::new([1 << 31, 2, 1]));
+        assert!(a.bit(33) && !a.bit(32));
+        a.toggle_bit(33);
+        a.toggle_bit(34);
+        a.clear_bit(95);
+        a.clear_bit(94);
+        assert_eq!(a, Uint::new([0, 4, 1]));
+        assert_eq!(a.bits(), 35);
+        assert_eq!(Uint::<3>::ZERO.bits(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bit_out_of_range() {
+        Uint::<3>::ZERO.bit(96);
+    }
+
+    #[test]
+    fn shifts() {
+        let a = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128;
+        let my_a = Uint::<4>::from_u128(a);
+        let mut b = my_a;
+        b <<= 33;
+        b >>= 1;
+        assert_eq!(b.to_u128(), Some((a << 33) >> 1));
+        b >>= 100;
+        assert_eq!(b.to_u128(), Some((a << 33) >> 101));
+    }
+}
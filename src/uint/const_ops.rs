@@ -0,0 +1,302 @@
+//! `const` versions of the arithmetic operators, so constants like moduli, powers of ten and lookup
+//! tables can be computed at compile time.
+//!
+//! Unlike the operators, the arithmetic functions always panic on overflow, which turns into a compile
+//! error when they are evaluated in a constant. The exception is `const_shl`, which drops the bits
+//! shifted out of the top like `<<` on primitive integers, and only panics if the shift is too large.
+//! These functions are slower than the operators, so use those at runtime.
+
+use core::cmp::Ordering;
+
+use crate::modular::{add, sub};
+use crate::uint::Uint;
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Returns `self == rhs`.
+    pub const fn const_eq(&self, rhs: &Self) -> bool {
+        matches!(self.const_cmp(rhs), Ordering::Equal)
+    }
+
+    /// Compares `self` to `rhs`, like [`Ord::cmp`].
+    pub const fn const_cmp(&self, rhs: &Self) -> Ordering {
+        let mut i = 0;
+        while i < SIZE {
+            if self.digits[i] != rhs.digits[i] {
+                return if self.digits[i] < rhs.digits[i] {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+            i += 1;
+        }
+        Ordering::Equal
+    }
+
+    /// Calculates `self + rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result does not fit into `SIZE` digits.
+    pub const fn const_add(self, rhs: Self) -> Self {
+        let (digits, carry) = add(&self.digits, &rhs.digits);
+        assert!(!carry, "attempt to add with overflow");
+        Uint { digits }
+    }
+
+    /// Calculates `self - rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs > self`.
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        let (digits, borrow) = sub(&self.digits, &rhs.digits);
+        assert!(!borrow, "attempt to subtract with overflow");
+        Uint { digits }
+    }
+
+    /// Calculates `self * rhs` using schoolbook multiplication.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result does not fit into `SIZE` digits.
+    pub const fn const_mul(self, rhs: Self) -> Self {
+        let (a, b) = (&self.digits, &rhs.digits);
+        let mut out = [0u32; SIZE];
+        // `wa` and `wb` are the weights of the digits, counted from the least significant one
+        let mut wb = 0;
+        while wb < SIZE {
+            let d = b[SIZE - 1 - wb] as u64;
+            if d != 0 {
+                let mut carry = 0u64;
+                let mut wa = 0;
+                while wa + wb < SIZE {
+                    let k = SIZE - 1 - (wa + wb);
+                    let x = out[k] as u64 + a[SIZE - 1 - wa] as u64 * d + carry;
+                    out[k] = x as u32;
+                    carry = x >> 32;
+                    wa += 1;
+                }
+                // the carry and the remaining digits of `a` would need a weight of at least SIZE
+                let mut overflow = carry != 0;
+                let mut i = 0;
+                while i < wb {
+                    overflow |= a[i] != 0;
+                    i += 1;
+                }
+                assert!(!overflow, "attempt to multiply with overflow");
+            }
+            wb += 1;
+        }
+        Uint { digits: out }
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// ```
+    /// use large::Uint;
+    ///
+    /// const TEN_POW_30: Uint<4> = Uint::from_u32(10).const_pow(30);
+    /// assert_eq!(TEN_POW_30, Uint::from_u128(10u128.pow(30)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result does not fit into `SIZE` digits.
+    pub const fn const_pow(self, mut exp: u32) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+        let mut base = self;
+        let mut acc = Self::ONE;
+        while exp > 1 {
+            if exp & 1 == 1 {
+                acc = acc.const_mul(base);
+            }
+            exp /= 2;
+            base = base.const_mul(base);
+        }
+        // the base is not squared after the last bit, since that could overflow needlessly
+        acc.const_mul(base)
+    }
+
+    /// Calculates `self << rhs`. Bits which are shifted out are lost, like with primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs >= Self::BITS`.
+    pub const fn const_shl(self, rhs: u32) -> Self {
+        assert!(rhs < Self::BITS, "attempt to shift left with overflow");
+        let (n, bits) = ((rhs / 32) as usize, rhs % 32);
+        let mut out = [0u32; SIZE];
+        let mut k = 0;
+        while k + n < SIZE {
+            out[k] = self.digits[k + n] << bits;
+            if bits > 0 && k + n + 1 < SIZE {
+                out[k] |= self.digits[k + n + 1] >> (32 - bits);
+            }
+            k += 1;
+        }
+        Uint { digits: out }
+    }
+
+    /// Calculates `self >> rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs >= Self::BITS`.
+    pub const fn const_shr(self, rhs: u32) -> Self {
+        assert!(rhs < Self::BITS, "attempt to shift right with overflow");
+        let (n, bits) = ((rhs / 32) as usize, rhs % 32);
+        let mut out = [0u32; SIZE];
+        let mut k = n;
+        while k < SIZE {
+            out[k] = self.digits[k - n] >> bits;
+            if bits > 0 && k > n {
+                out[k] |= self.digits[k - n - 1] << (32 - bits);
+            }
+            k += 1;
+        }
+        Uint { digits: out }
+    }
+
+    /// Divides `self` by `rhs`, returning `(self / rhs, self % rhs)`.
+    ///
+    /// This is a bitwise long division, which takes one subtraction per bit of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    pub const fn const_div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(!rhs.const_eq(&Self::ZERO), "attempt to divide by zero");
+        let mut quot = [0u32; SIZE];
+        let mut rem = Self::ZERO;
+        let mut i = 0;
+        while i < Self::BITS {
+            let (idx, shift) = ((i / 32) as usize, 31 - i % 32);
+            // rem = 2 * rem + bit, which may need one bit more than `SIZE` digits
+            let carry = rem.digits[0] >> 31 == 1;
+            rem = rem.const_shl(1);
+            rem.digits[SIZE - 1] |= (self.digits[idx] >> shift) & 1;
+            // rem < 2 * rhs, so a single subtraction is enough
+            if carry || !matches!(rem.const_cmp(&rhs), Ordering::Less) {
+                rem = Uint {
+                    digits: sub(&rem.digits, &rhs.digits).0,
+                };
+                quot[idx] |= 1 << shift;
+            }
+            i += 1;
+        }
+        (Uint { digits: quot }, rem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use num_bigint::BigUint;
+
+    use crate::Uint;
+
+    // evaluated at compile time
+    const P127: Uint<4> = Uint::<4>::ONE.const_shl(127).const_sub(Uint::ONE);
+    const TEN_POW_38: Uint<4> = Uint::from_u32(10).const_pow(38);
+    const SQUARE: Uint<8> = Uint::<8>::MAX
+        .const_shr(128)
+        .const_mul(Uint::MAX.const_shr(128));
+    const DIV_REM: (Uint<4>, Uint<4>) = TEN_POW_38.const_div_rem(Uint::from_u64(1 << 40));
+
+    #[test]
+    fn constants() {
+        assert_eq!(P127, Uint::from_u128(u128::MAX >> 1));
+        assert_eq!(TEN_POW_38, Uint::from_u128(10u128.pow(38)));
+        assert_eq!(SQUARE, BigUint::from(u128::MAX).pow(2));
+        let (q, r) = DIV_REM;
+        assert_eq!(
+            (q.to_u128(), r.to_u128()),
+            (Some(10u128.pow(38) >> 40), Some(10u128.pow(38) % (1 << 40)))
+        );
+    }
+
+    #[test]
+    fn against_u128() {
+        for _ in 0..1_000 {
+            let (a, b) = (rand::random::<u128>(), rand::random::<u128>());
+            let (a, b) = (a >> (a % 128), b >> (b % 128));
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+
+            assert_eq!(my_a.const_eq(&my_b), a == b);
+            assert!(my_a.const_eq(&my_a));
+            assert_eq!(my_a.const_cmp(&my_b), a.cmp(&b));
+            if let Some(sum) = a.checked_add(b) {
+                assert_eq!(my_a.const_add(my_b).to_u128(), Some(sum));
+            }
+            if let Some(diff) = a.checked_sub(b) {
+                assert_eq!(my_a.const_sub(my_b).to_u128(), Some(diff));
+            }
+            if let Some(product) = a.checked_mul(b) {
+                assert_eq!(my_a.const_mul(my_b).to_u128(), Some(product));
+            }
+            if let Some(quot) = a.checked_div(b) {
+                let (q, r) = my_a.const_div_rem(my_b);
+                assert_eq!((q.to_u128(), r.to_u128()), (Some(quot), Some(a % b)));
+            }
+            let shift = (b % 128) as u32;
+            assert_eq!(my_a.const_shl(shift).to_u128(), Some(a << shift));
+            assert_eq!(my_a.const_shr(shift).to_u128(), Some(a >> shift));
+            let exp = (b % 8) as u32;
+            if let Some(pow) = a.checked_pow(exp) {
+                assert_eq!(my_a.const_pow(exp).to_u128(), Some(pow));
+            }
+        }
+        assert_eq!(Uint::<4>::MAX.const_cmp(&Uint::ZERO), Ordering::Greater);
+        assert_eq!(Uint::<4>::ZERO.const_pow(0), Uint::ONE);
+    }
+
+    #[test]
+    fn against_num_bigint() {
+        for _ in 0..100 {
+            let a = Uint::<8>::new(rand::random());
+            let b = Uint::<8>::new(rand::random()) >> (rand::random::<u8>() as u64);
+            if b == Uint::ZERO {
+                continue;
+            }
+            let (q, r) = a.const_div_rem(b);
+            let (big_a, big_b) = (BigUint::from(a), BigUint::from(b));
+            assert_eq!(q, &big_a / &big_b);
+            assert_eq!(r, &big_a % &big_b);
+
+            let (low_a, low_b) = (a.const_shr(128), b.const_shr(128));
+            assert_eq!(
+                low_a.const_mul(low_b),
+                BigUint::from(low_a) * BigUint::from(low_b)
+            );
+            assert_eq!(a.const_div_rem(a), (Uint::ONE, Uint::ZERO));
+        }
+        // a divisor with the top bit set, where the remainder temporarily needs one bit more
+        let b = Uint::<8>::MAX.const_sub(Uint::from_u32(5));
+        assert_eq!(
+            Uint::<8>::MAX.const_div_rem(b),
+            (Uint::ONE, Uint::from_u32(5))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_overflow() {
+        (Uint::<4>::ONE.const_shl(64)).const_mul(Uint::ONE.const_shl(64));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        Uint::<4>::MAX.const_add(Uint::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_by_zero() {
+        Uint::<4>::ONE.const_div_rem(Uint::ZERO);
+    }
+}
@@ -1,15 +1,28 @@
 use crate::uint::Uint;
+use crate::TryFromIntError;
 
+// fails to compile when `SIZE < MIN` once `OK` is used with concrete values
+pub(crate) struct AssertMinSize<const SIZE: usize, const MIN: usize>;
+
+impl<const SIZE: usize, const MIN: usize> AssertMinSize<SIZE, MIN> {
+    pub(crate) const OK: () = assert!(
+        SIZE >= MIN,
+        "the number is too small to hold the primitive"
+    );
+}
+
+// The `from_*` functions reject sizes which are too small to hold every value of the primitive type
+// at compile time. Use `try_from_*` to convert values into smaller numbers.
 impl<const SIZE: usize> Uint<SIZE> {
     pub const fn from_u32(v: u32) -> Self {
-        assert!(SIZE >= 1);
+        let () = AssertMinSize::<SIZE, 1>::OK;
         let mut data = [0; SIZE];
         data[SIZE - 1] = v;
         Uint { digits: data }
     }
 
     pub const fn from_u64(v: u64) -> Self {
-        assert!(SIZE >= 2);
+        let () = AssertMinSize::<SIZE, 2>::OK;
         let mut data = [0; SIZE];
         data[SIZE - 1] = v as u32;
         data[SIZE - 2] = (v >> 32) as u32;
@@ -17,7 +30,7 @@ impl<const SIZE: usize> Uint<SIZE> {
     }
 
     pub const fn from_u128(v: u128) -> Self {
-        assert!(SIZE >= 4);
+        let () = AssertMinSize::<SIZE, 4>::OK;
         let mut data = [0; SIZE];
         data[SIZE - 1] = v as u32;
         data[SIZE - 2] = (v >> 32) as u32;
@@ -25,7 +38,17 @@ impl<const SIZE: usize> Uint<SIZE> {
         data[SIZE - 4] = (v >> 96) as u32;
         Uint { digits: data }
     }
-    
+
+    /// Converts a `u64` to a `Uint` of any size, returning `None` if it doesn't fit.
+    pub fn try_from_u64(v: u64) -> Option<Self> {
+        Self::try_from_le_slice(&v.to_le_bytes())
+    }
+
+    /// Converts a `u128` to a `Uint` of any size, returning `None` if it doesn't fit.
+    pub fn try_from_u128(v: u128) -> Option<Self> {
+        Self::try_from_le_slice(&v.to_le_bytes())
+    }
+
     pub fn to_u128(self) -> Option<u128> {
         let digits = self.digits_be();
         if digits.len() > 4 {
@@ -41,6 +64,99 @@ impl<const SIZE: usize> Uint<SIZE> {
     }
 }
 
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Returns all digits of this number, including leading zeros, in big-endian order.
+    pub const fn as_limbs(&self) -> &[u32; SIZE] {
+        &self.digits
+    }
+
+    /// Returns a mutable reference to all digits of this number, including leading zeros, in
+    /// big-endian order. Any change to the digits changes the number.
+    pub fn as_limbs_mut(&mut self) -> &mut [u32; SIZE] {
+        &mut self.digits
+    }
+
+    /// Returns all digits of this number, including leading zeros, in little-endian order.
+    pub fn to_limbs_le(&self) -> [u32; SIZE] {
+        let mut limbs = self.digits;
+        limbs.reverse();
+        limbs
+    }
+
+    /// Creates a number from its digits in big-endian order. This is the same as [`Uint::new`].
+    pub const fn from_limbs_be(limbs: [u32; SIZE]) -> Self {
+        Uint { digits: limbs }
+    }
+
+    /// Creates a number from its digits in little-endian order.
+    pub fn from_limbs_le(mut limbs: [u32; SIZE]) -> Self {
+        limbs.reverse();
+        Uint { digits: limbs }
+    }
+
+    /// Creates a number from a big-endian byte slice of arbitrary length.
+    /// Returns `None` if the number does not fit into `SIZE` digits.
+    pub fn try_from_be_slice(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_le_bytes_iter(bytes.iter().rev().copied())
+    }
+
+    /// Creates a number from a little-endian byte slice of arbitrary length.
+    /// Returns `None` if the number does not fit into `SIZE` digits.
+    pub fn try_from_le_slice(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_le_bytes_iter(bytes.iter().copied())
+    }
+
+    fn try_from_le_bytes_iter(bytes: impl Iterator<Item = u8>) -> Option<Self> {
+        let mut out = Self::ZERO;
+        for (i, byte) in bytes.enumerate() {
+            if i / 4 >= SIZE {
+                if byte != 0 {
+                    return None;
+                }
+                continue;
+            }
+            out.digits[SIZE - 1 - i / 4] |= (byte as u32) << (8 * (i % 4));
+        }
+        Some(out)
+    }
+}
+
+impl<const SIZE: usize> Uint<SIZE>
+where
+    [(); SIZE * 4]:,
+{
+    /// Returns the memory representation of this integer as a byte array in big-endian byte order.
+    pub fn to_be_bytes(&self) -> [u8; SIZE * 4] {
+        let mut bytes = [0; SIZE * 4];
+        for (i, digit) in self.digits.iter().enumerate() {
+            bytes[i * 4..(i + 1) * 4].copy_from_slice(&digit.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the memory representation of this integer as a byte array in little-endian byte order.
+    pub fn to_le_bytes(&self) -> [u8; SIZE * 4] {
+        let mut bytes = self.to_be_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Creates an integer value from its representation as a byte array in big endian.
+    pub fn from_be_bytes(bytes: [u8; SIZE * 4]) -> Self {
+        let mut out = Self::ZERO;
+        for (i, digit) in out.digits.iter_mut().enumerate() {
+            *digit = u32::from_be_bytes(bytes[i * 4..(i + 1) * 4].try_into().unwrap());
+        }
+        out
+    }
+
+    /// Creates an integer value from its representation as a byte array in little endian.
+    pub fn from_le_bytes(mut bytes: [u8; SIZE * 4]) -> Self {
+        bytes.reverse();
+        Self::from_be_bytes(bytes)
+    }
+}
+
 impl<const S: usize> From<u8> for Uint<S> {
     fn from(v: u8) -> Self {
         Uint::from_u32(v as u32)
@@ -69,4 +185,106 @@ impl<const S: usize> From<u128> for Uint<S> {
     fn from(v: u128) -> Self {
         Uint::from_u128(v)
     }
-}
\ No newline at end of file
+}
+
+macro_rules! impl_try_from_uint {
+    ($($t:ty),*) => {
+        $(
+            impl<const S: usize> TryFrom<Uint<S>> for $t {
+                type Error = TryFromIntError;
+
+                fn try_from(v: Uint<S>) -> Result<Self, Self::Error> {
+                    v.to_u128()
+                        .and_then(|v| <$t>::try_from(v).ok())
+                        .ok_or(TryFromIntError(()))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_uint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::Uint;
+
+    #[test]
+    fn bytes_against_u128() {
+        fn check(a: u128) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.to_be_bytes(), a.to_be_bytes());
+            assert_eq!(my_a.to_le_bytes(), a.to_le_bytes());
+            assert_eq!(Uint::<4>::from_be_bytes(a.to_be_bytes()), my_a);
+            assert_eq!(Uint::<4>::from_le_bytes(a.to_le_bytes()), my_a);
+            assert_eq!(Uint::<4>::try_from_be_slice(&a.to_be_bytes()), Some(my_a));
+            assert_eq!(Uint::<4>::try_from_le_slice(&a.to_le_bytes()), Some(my_a));
+        }
+
+        check(0);
+        check(u128::MAX);
+        for _ in 0..1_000 {
+            check(rand::random());
+        }
+    }
+
+    #[test]
+    fn slices() {
+        assert_eq!(Uint::<2>::try_from_be_slice(&[]), Some(Uint::ZERO));
+        assert_eq!(Uint::<2>::try_from_be_slice(&[1, 2]), Some(Uint::from_u32(0x0102)));
+        assert_eq!(Uint::<2>::try_from_le_slice(&[1, 2]), Some(Uint::from_u32(0x0201)));
+        assert_eq!(
+            Uint::<1>::try_from_be_slice(&[0, 0, 0xaa, 0xbb, 0xcc, 0xdd]),
+            Some(Uint::from_u32(0xaabbccdd))
+        );
+        assert_eq!(Uint::<1>::try_from_be_slice(&[1, 0, 0, 0, 0]), None);
+        assert_eq!(Uint::<1>::try_from_le_slice(&[0, 0, 0, 0, 1]), None);
+    }
+
+    #[test]
+    fn small_sizes() {
+        assert_eq!(Uint::<1>::try_from_u64(5), Some(Uint::from_u32(5)));
+        assert_eq!(Uint::<1>::try_from_u64(u32::MAX as u64 + 1), None);
+        assert_eq!(Uint::<2>::try_from_u64(u64::MAX), Some(Uint::MAX));
+        assert_eq!(
+            Uint::<3>::try_from_u128(u64::MAX as u128),
+            Some(Uint::new([0, u32::MAX, u32::MAX]))
+        );
+        assert_eq!(Uint::<3>::try_from_u128(u128::MAX), None);
+        assert_eq!(Uint::<5>::try_from_u128(u128::MAX), Some(Uint::from_u128(u128::MAX)));
+    }
+
+    #[test]
+    fn try_into_primitives() {
+        let v = Uint::<3>::from_u32(200);
+        assert_eq!(u8::try_from(v), Ok(200));
+        assert!(i8::try_from(v).is_err());
+        assert_eq!(i16::try_from(v), Ok(200));
+        assert_eq!(usize::try_from(v), Ok(200));
+
+        let v = Uint::<3>::new([0, u32::MAX, u32::MAX]);
+        assert_eq!(u64::try_from(v), Ok(u64::MAX));
+        assert!(i64::try_from(v).is_err());
+        assert!(u32::try_from(v).is_err());
+        assert_eq!(i128::try_from(v), Ok(u64::MAX as i128));
+
+        let v = Uint::<5>::MAX;
+        assert!(u128::try_from(v).is_err());
+        assert!(u128::try_from(v >> 32u64).is_ok());
+        assert!(i128::try_from(v >> 32u64).is_err());
+        assert_eq!(u8::try_from(Uint::<5>::ZERO), Ok(0));
+    }
+
+    #[test]
+    fn limbs() {
+        let a = Uint::<3>::new([1, 2, 3]);
+        assert_eq!(a.as_limbs(), &[1, 2, 3]);
+        assert_eq!(a.to_limbs_le(), [3, 2, 1]);
+        assert_eq!(Uint::from_limbs_le([3, 2, 1]), a);
+        assert_eq!(Uint::from_limbs_be([1, 2, 3]), a);
+
+        let mut b = a;
+        b.as_limbs_mut()[0] = 0;
+        assert_eq!(b, Uint::new([0, 2, 3]));
+    }
+}
@@ -0,0 +1,305 @@
+//! Operations whose running time only depends on `SIZE`, not on the values involved.
+//!
+//! Most other operations on `Uint` take shortcuts depending on the values, like skipping leading
+//! zero digits or returning early for a divisor of one, which leaks information about secret values
+//! through timing. The functions in here process every digit the same way, and replace branches by
+//! masking with a [`Choice`].
+
+use core::hint::black_box;
+use core::ops::{BitAnd, BitOr, Not};
+
+use super::Uint;
+
+/// The result of a constant-time comparison, which can be used to select between values without
+/// branching.
+///
+/// Internally, this is a mask which has either all or no bits set.
+#[derive(Copy, Clone, Debug)]
+pub struct Choice(u32);
+
+impl Choice {
+    // `bit` must be 0 or 1.
+    // `black_box` keeps the compiler from turning the masking based on this back into a branch.
+    fn from_bit(bit: u32) -> Self {
+        debug_assert!(bit <= 1);
+        Choice(black_box(bit).wrapping_neg())
+    }
+
+    // returns true if `x` is not zero
+    fn from_nonzero(x: u32) -> Self {
+        Self::from_bit((x | x.wrapping_neg()) >> 31)
+    }
+
+    fn mask(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(b: bool) -> Self {
+        Choice::from_bit(b as u32)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(c: Choice) -> Self {
+        c.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Self::Output {
+        Choice(!self.0)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Choice(self.0 | rhs.0)
+    }
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Returns whether `self == other` in constant time.
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        let mut diff = 0;
+        for i in 0..SIZE {
+            diff |= self.digits[i] ^ other.digits[i];
+        }
+        !Choice::from_nonzero(diff)
+    }
+
+    /// Returns whether `self < other` in constant time.
+    pub fn ct_lt(&self, other: &Self) -> Choice {
+        let (_, borrow) = self.overflowing_sub(*other);
+        Choice::from(borrow)
+    }
+
+    /// Returns whether `self > other` in constant time.
+    pub fn ct_gt(&self, other: &Self) -> Choice {
+        other.ct_lt(self)
+    }
+
+    /// Returns `b` if `choice` is true and `a` otherwise, in constant time.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = *a;
+        out.conditional_assign(b, choice);
+        out
+    }
+
+    /// Sets `self` to `other` if `choice` is true, in constant time.
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for i in 0..SIZE {
+            self.digits[i] ^= (self.digits[i] ^ other.digits[i]) & choice.mask();
+        }
+    }
+
+    /// Swaps `a` and `b` if `choice` is true, in constant time.
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for i in 0..SIZE {
+            let t = (a.digits[i] ^ b.digits[i]) & choice.mask();
+            a.digits[i] ^= t;
+            b.digits[i] ^= t;
+        }
+    }
+
+    /// Calculates `(self + rhs) mod modulus` in constant time.
+    ///
+    /// `self` and `rhs` must be less than `modulus`, otherwise the result is unspecified.
+    pub fn ct_add_mod(self, rhs: Self, modulus: Self) -> Self {
+        debug_assert!(self < modulus && rhs < modulus);
+        let (sum, carry) = self.overflowing_add(rhs);
+        // if the addition overflowed, the sum is at least 2^(32 * SIZE) > modulus, and the
+        // wrapping subtraction yields the correct result.
+        let (reduced, borrow) = sum.overflowing_sub(modulus);
+        Self::conditional_select(&sum, &reduced, Choice::from(carry) | !Choice::from(borrow))
+    }
+
+    /// Calculates `(self - rhs) mod modulus` in constant time.
+    ///
+    /// `self` and `rhs` must be less than `modulus`, otherwise the result is unspecified.
+    pub fn ct_sub_mod(self, rhs: Self, modulus: Self) -> Self {
+        debug_assert!(self < modulus && rhs < modulus);
+        let (diff, borrow) = self.overflowing_sub(rhs);
+        let correction = Self::conditional_select(&Self::ZERO, &modulus, Choice::from(borrow));
+        diff.wrapping_add(correction)
+    }
+
+    /// Calculates `(self * rhs) mod modulus` in constant time.
+    ///
+    /// The product is computed by doubling and adding modulo `modulus` for every bit of `rhs`, so
+    /// this doesn't need any division.
+    /// `self` and `rhs` must be less than `modulus`, otherwise the result is unspecified.
+    pub fn ct_mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        debug_assert!(self < modulus && rhs < modulus);
+        let mut acc = Self::ZERO;
+        for digit in rhs.digits {
+            for bit in (0..32).rev() {
+                acc = acc.ct_add_mod(acc, modulus);
+                let summand = Self::conditional_select(
+                    &Self::ZERO,
+                    &self,
+                    Choice::from_bit((digit >> bit) & 1),
+                );
+                acc = acc.ct_add_mod(summand, modulus);
+            }
+        }
+        acc
+    }
+
+    /// Calculates `self^exp mod modulus` in constant time, using the Montgomery ladder.
+    ///
+    /// Unlike [`Uint::pow_mod`], this performs the same operations for every bit of `exp`,
+    /// including its leading zeros.
+    /// `self` must be less than `modulus`, otherwise the result is unspecified.
+    pub fn ct_pow_mod(self, exp: Self, modulus: Self) -> Self {
+        debug_assert!(self < modulus);
+        // `ONE mod modulus`, since the modulus might be one
+        let mut r0 = Self::conditional_select(&Self::ONE, &Self::ZERO, modulus.ct_eq(&Self::ONE));
+        // invariant: r1 = r0 * self
+        let mut r1 = self;
+        for digit in exp.digits {
+            for bit in (0..32).rev() {
+                let choice = Choice::from_bit((digit >> bit) & 1);
+                Self::conditional_swap(&mut r0, &mut r1, choice);
+                r1 = r0.ct_mul_mod(r1, modulus);
+                r0 = r0.ct_mul_mod(r0, modulus);
+                Self::conditional_swap(&mut r0, &mut r1, choice);
+            }
+        }
+        r0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize> zeroize::Zeroize for Uint<SIZE> {
+    fn zeroize(&mut self) {
+        self.digits.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    use super::Choice;
+    use crate::Uint;
+
+    #[test]
+    fn choice() {
+        let (t, f) = (Choice::from(true), Choice::from(false));
+        assert!(bool::from(t));
+        assert!(!bool::from(f));
+        assert!(bool::from(!f));
+        assert!(!bool::from(t & f));
+        assert!(bool::from(t | f));
+    }
+
+    #[test]
+    fn compare_against_u128() {
+        fn check(a: u128, b: u128) {
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+            assert_eq!(bool::from(my_a.ct_eq(&my_b)), a == b);
+            assert_eq!(bool::from(my_a.ct_lt(&my_b)), a < b);
+            assert_eq!(bool::from(my_a.ct_gt(&my_b)), a > b);
+        }
+
+        check(0, 0);
+        check(u128::MAX, u128::MAX);
+        check(0, u128::MAX);
+        check(1 << 64, 1 << 63);
+        for _ in 0..1_000 {
+            let a = rand::random();
+            check(a, a);
+            check(a, a ^ 1 << rand::thread_rng().gen_range(0..128));
+            check(a, rand::random());
+        }
+    }
+
+    #[test]
+    fn select_and_swap() {
+        let a = Uint::<4>::from_u128(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+        let b = Uint::<4>::MAX - a;
+        assert_eq!(Uint::conditional_select(&a, &b, Choice::from(false)), a);
+        assert_eq!(Uint::conditional_select(&a, &b, Choice::from(true)), b);
+
+        let mut c = a;
+        c.conditional_assign(&b, Choice::from(false));
+        assert_eq!(c, a);
+        c.conditional_assign(&b, Choice::from(true));
+        assert_eq!(c, b);
+
+        let (mut x, mut y) = (a, b);
+        Uint::conditional_swap(&mut x, &mut y, Choice::from(false));
+        assert_eq!((x, y), (a, b));
+        Uint::conditional_swap(&mut x, &mut y, Choice::from(true));
+        assert_eq!((x, y), (b, a));
+    }
+
+    fn random_below(m: Uint<8>) -> Uint<8> {
+        let v = Uint::new(rand::random());
+        v % m
+    }
+
+    #[test]
+    fn mod_ops_against_num_bigint() {
+        fn check(a: Uint<8>, b: Uint<8>, m: Uint<8>) {
+            let (big_a, big_b, big_m) = (BigUint::from(a), BigUint::from(b), BigUint::from(m));
+            assert_eq!(a.ct_add_mod(b, m), (&big_a + &big_b) % &big_m);
+            assert_eq!(a.ct_sub_mod(b, m), (&big_a + &big_m - &big_b) % &big_m);
+            assert_eq!(a.ct_mul_mod(b, m), (&big_a * &big_b) % &big_m);
+        }
+
+        check(Uint::ZERO, Uint::ZERO, Uint::ONE);
+        check(Uint::MAX - Uint::ONE, Uint::MAX - Uint::ONE, Uint::MAX);
+        check(Uint::ZERO, Uint::MAX - Uint::ONE, Uint::MAX);
+        for _ in 0..100 {
+            let m = Uint::new(rand::random());
+            check(random_below(m), random_below(m), m);
+            let m = Uint::from_u64(rand::random::<u64>() | 1);
+            check(random_below(m), random_below(m), m);
+        }
+    }
+
+    #[test]
+    fn pow_mod_against_num_bigint() {
+        fn check(a: Uint<8>, exp: Uint<8>, m: Uint<8>) {
+            let c = BigUint::from(a).modpow(&BigUint::from(exp), &BigUint::from(m));
+            assert_eq!(a.ct_pow_mod(exp, m), c);
+            assert_eq!(a.ct_pow_mod(exp, m), a.pow_mod(exp, m));
+        }
+
+        check(Uint::ZERO, Uint::ZERO, Uint::ONE);
+        check(Uint::ZERO, Uint::ZERO, Uint::MAX);
+        check(Uint::MAX - Uint::ONE, Uint::MAX, Uint::MAX);
+        check(Uint::TWO, Uint::from_u32(1000), Uint::from_u32(1_000_000_007));
+        for _ in 0..4 {
+            let m = Uint::new(rand::random());
+            check(random_below(m), Uint::new(rand::random()), m);
+            check(random_below(m), Uint::from_u32(rand::random()), m);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut v = Uint::<4>::MAX;
+        v.zeroize();
+        assert_eq!(v, Uint::ZERO);
+    }
+}
@@ -1,8 +1,8 @@
 use crate::uint::Uint;
 use crate::B;
-use std::any::Any;
-use std::cmp::Ordering;
-use std::ops::{Div, DivAssign, Rem, RemAssign};
+use core::any::Any;
+use core::cmp::Ordering;
+use core::ops::{Div, DivAssign, Rem, RemAssign};
 
 impl<const SIZE: usize> Div for Uint<SIZE>
 where
@@ -44,6 +44,114 @@ where
     }
 }
 
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Divides `self` by a single digit, returning `(self / rhs, self % rhs)`.
+    /// This is a single pass over the digits of `self`, which is a lot faster than [`Uint::div_rem`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn div_rem_u32(mut self, rhs: u32) -> (Self, u32) {
+        let rem = self.div_rem_u32_assign(rhs);
+        (self, rem)
+    }
+
+    /// Divides `self` by a `u64`, returning `(self / rhs, self % rhs)`.
+    /// Like [`Uint::div_rem_u32`], this is a single pass over the digits of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn div_rem_u64(mut self, rhs: u64) -> (Self, u64) {
+        if let Ok(rhs) = u32::try_from(rhs) {
+            let rem = self.div_rem_u32_assign(rhs);
+            return (self, rem as u64);
+        }
+
+        // the remainder is always smaller than `rhs`, so every digit of the quotient fits in a u32
+        let msd_idx = self.msd_idx();
+        let mut rem = 0u128;
+        for digit in &mut self.digits[msd_idx..] {
+            let cur = (rem << 32) | *digit as u128;
+            *digit = (cur / rhs as u128) as u32;
+            rem = cur % rhs as u128;
+        }
+        (self, rem as u64)
+    }
+
+    // Divides `self` in place by a single digit, returning the remainder.
+    // When inlined with a constant divisor, the compiler can replace the divisions by multiplications.
+    #[inline]
+    pub(crate) fn div_rem_u32_assign(&mut self, rhs: u32) -> u32 {
+        assert_ne!(rhs, 0, "attempt to divide by zero");
+        let msd_idx = self.msd_idx();
+        let mut rem = 0u64;
+        for digit in &mut self.digits[msd_idx..] {
+            let cur = (rem << 32) | *digit as u64;
+            *digit = (cur / rhs as u64) as u32;
+            rem = cur % rhs as u64;
+        }
+        rem as u32
+    }
+}
+
+impl<const SIZE: usize> Div<u32> for Uint<SIZE> {
+    type Output = Self;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        self.div_rem_u32(rhs).0
+    }
+}
+
+impl<const SIZE: usize> DivAssign<u32> for Uint<SIZE> {
+    fn div_assign(&mut self, rhs: u32) {
+        self.div_rem_u32_assign(rhs);
+    }
+}
+
+impl<const SIZE: usize> Rem<u32> for Uint<SIZE> {
+    type Output = Self;
+
+    fn rem(self, rhs: u32) -> Self::Output {
+        Uint::from_u32(self.div_rem_u32(rhs).1)
+    }
+}
+
+impl<const SIZE: usize> RemAssign<u32> for Uint<SIZE> {
+    fn rem_assign(&mut self, rhs: u32) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const SIZE: usize> Div<u64> for Uint<SIZE> {
+    type Output = Self;
+
+    fn div(self, rhs: u64) -> Self::Output {
+        self.div_rem_u64(rhs).0
+    }
+}
+
+impl<const SIZE: usize> DivAssign<u64> for Uint<SIZE> {
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const SIZE: usize> Rem<u64> for Uint<SIZE> {
+    type Output = Self;
+
+    fn rem(self, rhs: u64) -> Self::Output {
+        // the remainder is at most `self`, so it always fits
+        Uint::try_from_u64(self.div_rem_u64(rhs).1).unwrap()
+    }
+}
+
+impl<const SIZE: usize> RemAssign<u64> for Uint<SIZE> {
+    fn rem_assign(&mut self, rhs: u64) {
+        *self = *self % rhs;
+    }
+}
+
 impl<const SIZE: usize> Uint<SIZE>
 where
     [(); SIZE + 1]: Any,
@@ -57,6 +165,69 @@ where
         self._div_rem::<true>(b)
     }
 
+    /// Checked division and remainder. Returns `None` if `b` is zero.
+    pub fn checked_div_rem(self, b: Self) -> Option<(Uint<SIZE>, Uint<SIZE>)> {
+        if b == Uint::ZERO {
+            None
+        } else {
+            Some(self._div_rem::<true>(b))
+        }
+    }
+
+    /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Uint::ZERO {
+            None
+        } else {
+            Some(self._div_rem::<false>(rhs).0)
+        }
+    }
+
+    /// Checked integer remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, r)| r)
+    }
+
+    /// Calculates `self / rhs`.
+    ///
+    /// Division of unsigned integers never overflows, so the boolean is always `false`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self / rhs, false)
+    }
+
+    /// Calculates `self % rhs`.
+    ///
+    /// Division of unsigned integers never overflows, so the boolean is always `false`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self % rhs, false)
+    }
+
+    /// Wrapping division. Since division of unsigned integers never overflows, this is just `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Wrapping remainder. Since division of unsigned integers never overflows, this is just `self % rhs`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
     // `REM` indicates wheather the remainder should be calculated. If it's `false`, the remainder will be 0.
     fn _div_rem<const REM: bool>(self, b: Self) -> (Uint<SIZE>, Uint<SIZE>) {
         assert_ne!(b, Uint::ZERO, "attempt to divide by zero");
@@ -65,6 +236,12 @@ where
             return (self, Uint::ZERO);
         }
 
+        // a divisor with a single digit doesn't require the long division below
+        if b.significant_digits() == 1 {
+            let (q, r) = self.div_rem_u32(b.digits[SIZE - 1]);
+            return (q, Uint::from_u32(r));
+        }
+
         match self.cmp(&b) {
             Ordering::Less => return (Uint::ZERO, self),
             Ordering::Equal => return (Uint::ONE, Uint::ZERO),
@@ -138,7 +315,7 @@ where
         quotient.shr_digits(SIZE - quotient_digits);
 
         let rem = if REM {
-            idd.resized::<SIZE>() / Uint::from_u32(normalization_factor)
+            idd.resized::<SIZE>() / normalization_factor
         } else {
             Uint::ZERO
         };
@@ -244,4 +421,91 @@ mod tests {
 
         check_correct(my_a, my_b);
     }
+
+    #[test]
+    fn checked() {
+        fn check(a: u128, b: u128) {
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+            assert_eq!(my_a.checked_div(my_b).map(|r| r.to_u128().unwrap()), a.checked_div(b));
+            assert_eq!(my_a.checked_rem(my_b).map(|r| r.to_u128().unwrap()), a.checked_rem(b));
+        }
+
+        check(0, 0);
+        check(u128::MAX, 0);
+        check(u128::MAX, 1);
+        check(u128::MAX, 3);
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random::<u64>() as u128);
+        }
+    }
+
+    #[test]
+    fn small_divisors_against_u128() {
+        fn check(a: u128, b: u64) {
+            let my_a = Uint::<4>::from_u128(a);
+            let (q, r) = (a / b as u128, a % b as u128);
+
+            let (my_q, my_r) = my_a.div_rem_u64(b);
+            assert_eq!((my_q.to_u128(), my_r as u128), (Some(q), r));
+            assert_eq!((my_a / b).to_u128(), Some(q));
+            assert_eq!((my_a % b).to_u128(), Some(r));
+
+            if let Ok(b) = u32::try_from(b) {
+                let (my_q, my_r) = my_a.div_rem_u32(b);
+                assert_eq!((my_q.to_u128(), my_r as u128), (Some(q), r));
+                assert_eq!((my_a / b).to_u128(), Some(q));
+                assert_eq!((my_a % b).to_u128(), Some(r));
+
+                let mut x = my_a;
+                x /= b;
+                assert_eq!(x, my_q);
+                x = my_a;
+                x %= b;
+                assert_eq!(x.to_u128(), Some(r));
+            }
+        }
+
+        check(0, 1);
+        check(u128::MAX, 1);
+        check(u128::MAX, u32::MAX as u64);
+        check(u128::MAX, u64::MAX);
+        check(u128::MAX, u32::MAX as u64 + 1);
+        for _ in 0..1_000 {
+            let a = rand::random::<u128>() >> (rand::random::<u32>() % 128);
+            check(a, rand::random::<u32>().max(1) as u64);
+            check(a, rand::random::<u64>().max(1));
+        }
+    }
+
+    #[test]
+    fn small_divisors_against_num_bigint() {
+        for _ in 0..1_000 {
+            let a = Uint::<16>::new(rand::random());
+            let b = rand::random::<u64>().max(1);
+            let (q, r) = a.div_rem_u64(b);
+            let (c_q, c_r) = BigUint::from(a).div_rem(&BigUint::from(b));
+            assert_eq!(q, c_q);
+            assert_eq!(BigUint::from(r), c_r);
+
+            let (q, r) = a.div_rem_u32(b as u32 | 1);
+            let (c_q, c_r) = BigUint::from(a).div_rem(&BigUint::from(b as u32 | 1));
+            assert_eq!(q, c_q);
+            assert_eq!(BigUint::from(r), c_r);
+        }
+    }
+
+    #[test]
+    fn small_divisors_small_size() {
+        // the remainder of a division by a u64 always fits, even if it doesn't fit every u64
+        let a = Uint::<1>::from_u32(12345);
+        assert_eq!(a % u64::MAX, a);
+        assert_eq!(a / u64::MAX, Uint::ZERO);
+        assert_eq!(a % 100u64, Uint::from_u32(45));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_u32_by_zero() {
+        let _ = Uint::<4>::ONE / 0u32;
+    }
 }
@@ -0,0 +1,165 @@
+use crate::uint::Uint;
+use crate::TryFromIntError;
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Converts this number to the nearest `f64`, rounding ties to even.
+    /// Numbers too large to be represented yield `f64::INFINITY`.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        if bits <= 64 {
+            return self.top_u64(0) as f64;
+        }
+        if bits > 1024 {
+            return f64::INFINITY;
+        }
+
+        // the `as` cast rounds correctly, scaling by a power of two is exact (or overflows to inf).
+        let shift = bits - 64;
+        let scale = f64::from_bits(((1023 + shift) as u64) << 52);
+        self.top_u64(shift) as f64 * scale
+    }
+
+    /// Converts this number to the nearest `f32`, rounding ties to even.
+    /// Numbers too large to be represented yield `f32::INFINITY`.
+    pub fn to_f32(&self) -> f32 {
+        let bits = self.bits();
+        if bits <= 64 {
+            return self.top_u64(0) as f32;
+        }
+        if bits > 128 {
+            return f32::INFINITY;
+        }
+
+        let shift = bits - 64;
+        let scale = f32::from_bits((127 + shift) << 23);
+        self.top_u64(shift) as f32 * scale
+    }
+
+    // returns `self >> shift` as a u64, which must fit.
+    // if any of the shifted out bits are set, the LSB of the result is set, so that rounding the
+    // result to 53 or 24 bits behaves as if the shifted out bits were still there.
+    fn top_u64(&self, shift: u32) -> u64 {
+        let top = (*self >> shift as u64).to_limbs_le();
+        let mut out = top[0] as u64;
+        if SIZE > 1 {
+            out |= (top[1] as u64) << 32;
+        }
+        let sticky = shift > 0 && self.trailing_zeros() < shift;
+        out | sticky as u64
+    }
+
+    /// Converts a `f64` to a `Uint`, rounding towards zero.
+    /// Returns `None` if `v` is NaN, infinite, negative or does not fit into `SIZE` digits.
+    pub fn from_f64(v: f64) -> Option<Self> {
+        if !v.is_finite() || v < 0.0 {
+            return None;
+        }
+        if v < 1.0 {
+            return Some(Self::ZERO);
+        }
+
+        // v >= 1, so it is a normal number: v = mantissa * 2^exp
+        let bits = v.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        if exp < 0 {
+            // shifting out the fractional bits rounds towards zero
+            return Self::try_from_le_slice(&(mantissa >> -exp).to_le_bytes());
+        }
+        if 53 + exp as u32 > Self::BITS {
+            return None;
+        }
+        Some(Self::try_from_le_slice(&mantissa.to_le_bytes())? << exp as u64)
+    }
+
+    /// Converts a `f32` to a `Uint`, rounding towards zero.
+    /// Returns `None` if `v` is NaN, infinite, negative or does not fit into `SIZE` digits.
+    pub fn from_f32(v: f32) -> Option<Self> {
+        Self::from_f64(v as f64)
+    }
+}
+
+impl<const SIZE: usize> TryFrom<f64> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: f64) -> Result<Self, Self::Error> {
+        Uint::from_f64(v).ok_or(TryFromIntError(()))
+    }
+}
+
+impl<const SIZE: usize> TryFrom<f32> for Uint<SIZE> {
+    type Error = TryFromIntError;
+
+    fn try_from(v: f32) -> Result<Self, Self::Error> {
+        Uint::from_f32(v).ok_or(TryFromIntError(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uint;
+
+    #[test]
+    fn to_float_against_u128() {
+        fn check(a: u128) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.to_f64(), a as f64, "{:#x}", a);
+            assert_eq!(my_a.to_f32(), a as f32, "{:#x}", a);
+        }
+
+        check(0);
+        check(1);
+        check(u128::MAX);
+        // ties, rounding to even
+        check((1 << 100) + (1 << 47));
+        check((1 << 100) + (3 << 47));
+        check((1 << 100) + (1 << 47) + 1);
+        check((1 << 100) + (1 << 76));
+        check((1 << 100) + (3 << 76));
+        for shift in 0..128 {
+            check(rand::random::<u128>() >> shift);
+            check((rand::random::<u128>() >> shift) | 1 << (127 - shift));
+        }
+        for _ in 0..1_000 {
+            check(rand::random());
+        }
+    }
+
+    #[test]
+    fn to_float_large() {
+        assert_eq!(Uint::<40>::MAX.to_f64(), f64::INFINITY);
+        assert_eq!(Uint::<5>::MAX.to_f32(), f32::INFINITY);
+        assert_eq!(Uint::<4>::MAX.to_f32(), f32::INFINITY);
+        assert_eq!((Uint::<32>::ONE << 1023u64).to_f64(), 2f64.powi(1023));
+        assert_eq!((Uint::<32>::MAX >> 1u64).to_f64(), 2f64.powi(1023));
+        assert_eq!(Uint::<32>::MAX.to_f64(), f64::INFINITY);
+        let max = ((Uint::<32>::ONE << 53u64) - Uint::ONE) << 971u64;
+        assert_eq!(max.to_f64(), f64::MAX);
+        // rounds down to f64::MAX
+        assert_eq!((max + (Uint::ONE << 969u64)).to_f64(), f64::MAX);
+        // rounds up to 2^1024
+        assert_eq!((max + (Uint::ONE << 970u64)).to_f64(), f64::INFINITY);
+        assert_eq!((Uint::<8>::ONE << 200u64).to_f64(), 2f64.powi(200));
+    }
+
+    #[test]
+    fn from_float() {
+        assert_eq!(Uint::<1>::from_f64(0.0), Some(Uint::ZERO));
+        assert_eq!(Uint::<1>::from_f64(-0.0), Some(Uint::ZERO));
+        assert_eq!(Uint::<1>::from_f64(0.99), Some(Uint::ZERO));
+        assert_eq!(Uint::<1>::from_f64(12.7), Some(Uint::from_u32(12)));
+        assert_eq!(Uint::<1>::from_f64(u32::MAX as f64), Some(Uint::MAX));
+        assert_eq!(Uint::<1>::from_f64(u32::MAX as f64 + 1.0), None);
+        assert_eq!(Uint::<1>::from_f64(-1.0), None);
+        assert_eq!(Uint::<1>::from_f64(f64::NAN), None);
+        assert_eq!(Uint::<1>::from_f64(f64::INFINITY), None);
+        assert_eq!(Uint::<32>::from_f64(f64::MAX).map(|v| v.to_f64()), Some(f64::MAX));
+        assert_eq!(Uint::<2>::try_from(2f32.powi(63)), Ok(Uint::ONE << 63u64));
+        assert!(Uint::<2>::try_from(2f32.powi(64)).is_err());
+
+        for _ in 0..1_000 {
+            let v = rand::random::<u128>() as f64;
+            assert_eq!(Uint::<4>::from_f64(v).and_then(|v| v.to_u128()), Some(v as u128));
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use core::fmt::{self, Write};
+use core::str;
+
+use super::{Array2N, Uint};
+
+/// A buffer on the stack which is large enough to hold every digit of a `Uint<SIZE>` in any base,
+/// so formatting never needs to allocate.
+pub(crate) struct DigitBuf<const SIZE: usize>(Array2N<SIZE, [u8; 16]>);
+
+impl<const SIZE: usize> DigitBuf<SIZE> {
+    pub(crate) fn new() -> Self {
+        DigitBuf(Array2N::new([0; 16]))
+    }
+
+    // 32 bytes per digit of the number - enough for base 2, and therefore for every other base.
+    fn bytes(&mut self) -> &mut [u8] {
+        self.0.as_mut_slice().as_flattened_mut()
+    }
+}
+
+/// The padding which has to be written after the content, see [`write_pre_padding`].
+#[must_use]
+pub(crate) struct PostPadding {
+    fill: char,
+    count: usize,
+}
+
+impl PostPadding {
+    pub(crate) fn write(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.count {
+            f.write_char(self.fill)?;
+        }
+        Ok(())
+    }
+}
+
+/// Works like [`fmt::Formatter::pad_integral`] for content which is not available as one string.
+/// This writes the padding, sign and prefix in front of the content, which is `len` characters
+/// long. The caller then writes the content, followed by the returned [`PostPadding`].
+pub(crate) fn write_pre_padding(
+    f: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    prefix: &str,
+    len: usize,
+) -> Result<PostPadding, fmt::Error> {
+    let sign = match (is_nonnegative, f.sign_plus()) {
+        (false, _) => "-",
+        (true, true) => "+",
+        (true, false) => "",
+    };
+    let prefix = if f.alternate() { prefix } else { "" };
+    let len = len + sign.len() + prefix.chars().count();
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+
+    // `{:0}` puts the zeros between the sign and the digits, ignoring fill and alignment
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return Ok(PostPadding {
+            fill: '0',
+            count: 0,
+        });
+    }
+
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(prefix)?;
+    Ok(PostPadding { fill, count: post })
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    // returns the `width < 32` bits starting at bit `pos`
+    fn bits_at(&self, pos: u32, width: u32) -> u32 {
+        let limb = (pos / 32) as usize;
+        let offset = pos % 32;
+        let mut out = self.digits[SIZE - 1 - limb] >> offset;
+        if offset + width > 32 && limb + 1 < SIZE {
+            out |= self.digits[SIZE - 2 - limb] << (32 - offset);
+        }
+        out & ((1 << width) - 1)
+    }
+
+    // writes this number in base `2^shift` without leading zeros.
+    // since every digit maps to a fixed set of bits, no division is required here.
+    pub(crate) fn encode_pow2<'a>(
+        &self,
+        buf: &'a mut DigitBuf<SIZE>,
+        shift: u32,
+        upper: bool,
+    ) -> &'a str {
+        let alphabet: &[u8; 16] = match upper {
+            true => b"0123456789ABCDEF",
+            false => b"0123456789abcdef",
+        };
+        let len = self.bits().max(1).div_ceil(shift) as usize;
+        let bytes = &mut buf.bytes()[..len];
+        for (i, byte) in bytes.iter_mut().rev().enumerate() {
+            *byte = alphabet[self.bits_at(i as u32 * shift, shift) as usize];
+        }
+        str::from_utf8(bytes).unwrap()
+    }
+
+    fn fmt_pow2(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        shift: u32,
+        upper: bool,
+        prefix: &str,
+    ) -> fmt::Result {
+        let mut buf = DigitBuf::new();
+        f.pad_integral(true, prefix, self.encode_pow2(&mut buf, shift, upper))
+    }
+
+    /// Returns a wrapper which displays this number in decimal, with every group of three digits
+    /// separated by `separator`.
+    ///
+    /// ```
+    /// # use large::Uint;
+    /// let v = Uint::<2>::from_u64(1_234_567);
+    /// assert_eq!(format!("{}", v.grouped('_')), "1_234_567");
+    /// assert_eq!(format!("{:>10}", v.grouped(',')), " 1,234,567");
+    /// ```
+    pub fn grouped(self, separator: char) -> Grouped<SIZE> {
+        Grouped {
+            value: self,
+            separator,
+        }
+    }
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    // writes the decimal digits of this number without leading zeros to the end of `bytes`,
+    // returning the index of the first digit.
+    fn encode_decimal_bytes(&self, bytes: &mut [u8]) -> usize {
+        let mut chunks = Array2N::<SIZE, u32>::new(0);
+        let len = self.write_base_1e9_le(&mut chunks);
+
+        let mut start = bytes.len();
+        for i in 0..len {
+            let mut chunk = chunks[i];
+            for _ in 0..9 {
+                start -= 1;
+                bytes[start] = b'0' + (chunk % 10) as u8;
+                chunk /= 10;
+            }
+        }
+        // strip the leading zeros of the most significant chunk, but keep at least one digit
+        while start < bytes.len() - 1 && bytes[start] == b'0' {
+            start += 1;
+        }
+        start
+    }
+
+    pub(crate) fn encode_decimal<'a>(&self, buf: &'a mut DigitBuf<SIZE>) -> &'a str {
+        let bytes = buf.bytes();
+        let start = self.encode_decimal_bytes(bytes);
+        str::from_utf8(&bytes[start..]).unwrap()
+    }
+
+    // Formats this number in scientific notation, like the `LowerExp` implementations of the
+    // primitive integers do: trailing zeros are omitted, and if a precision is given, the
+    // mantissa is rounded half to even.
+    pub(crate) fn fmt_exp(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        is_nonnegative: bool,
+        upper: bool,
+    ) -> fmt::Result {
+        let mut buf = DigitBuf::<SIZE>::new();
+        let bytes = buf.bytes();
+        let start = self.encode_decimal_bytes(bytes);
+        let digits = &mut bytes[start..];
+
+        let mut exp = digits.len() - 1;
+        let mut end = digits.len();
+        while end > 1 && digits[end - 1] == b'0' {
+            end -= 1;
+        }
+
+        let mut trailing_zeros = 0;
+        match f.precision() {
+            Some(precision) if end - 1 > precision => {
+                let cut = precision + 1;
+                let rest_nonzero = digits[cut + 1..end].iter().any(|d| *d != b'0');
+                let round_up = match digits[cut] {
+                    b'5' => rest_nonzero || (digits[cut - 1] - b'0') % 2 == 1,
+                    d => d > b'5',
+                };
+                end = cut;
+                if round_up {
+                    let mut i = cut;
+                    loop {
+                        if i == 0 {
+                            // every digit was a 9, so 9.99 became 10.00
+                            digits[0] = b'1';
+                            exp += 1;
+                            break;
+                        }
+                        i -= 1;
+                        if digits[i] == b'9' {
+                            digits[i] = b'0';
+                        } else {
+                            digits[i] += 1;
+                            break;
+                        }
+                    }
+                }
+            }
+            Some(precision) => trailing_zeros = precision - (end - 1),
+            None => (),
+        }
+
+        let mut exp_buf = [0u8; 20];
+        let exp_start = encode_usize(exp, &mut exp_buf);
+        let exp_digits = str::from_utf8(&exp_buf[exp_start..]).unwrap();
+        let has_point = end > 1 || trailing_zeros > 0;
+        let len = end + has_point as usize + trailing_zeros + 1 + exp_digits.len();
+
+        let post = write_pre_padding(f, is_nonnegative, "", len)?;
+        f.write_char(digits[0] as char)?;
+        if has_point {
+            f.write_char('.')?;
+            f.write_str(str::from_utf8(&digits[1..end]).unwrap())?;
+            for _ in 0..trailing_zeros {
+                f.write_char('0')?;
+            }
+        }
+        f.write_char(if upper { 'E' } else { 'e' })?;
+        f.write_str(exp_digits)?;
+        post.write(f)
+    }
+}
+
+// writes the decimal digits of `v` to the end of `buf`, returning the index of the first digit
+fn encode_usize(mut v: usize, buf: &mut [u8; 20]) -> usize {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (v % 10) as u8;
+        v /= 10;
+        if v == 0 {
+            return start;
+        }
+    }
+}
+
+impl<const SIZE: usize> fmt::Display for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = DigitBuf::new();
+        f.pad_integral(true, "", self.encode_decimal(&mut buf))
+    }
+}
+
+impl<const SIZE: usize> fmt::Debug for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = DigitBuf::new();
+        f.write_str("0x")?;
+        f.write_str(self.encode_pow2(&mut buf, 4, false))
+    }
+}
+
+impl<const SIZE: usize> fmt::Binary for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 1, false, "0b")
+    }
+}
+
+impl<const SIZE: usize> fmt::Octal for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 3, false, "0o")
+    }
+}
+
+impl<const SIZE: usize> fmt::LowerHex for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 4, false, "0x")
+    }
+}
+
+impl<const SIZE: usize> fmt::UpperHex for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pow2(f, 4, true, "0x")
+    }
+}
+
+impl<const SIZE: usize> fmt::LowerExp for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, true, false)
+    }
+}
+
+impl<const SIZE: usize> fmt::UpperExp for Uint<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, true, true)
+    }
+}
+
+/// Displays a [`Uint`] in decimal with its digits in groups of three, see [`Uint::grouped`].
+#[derive(Copy, Clone, Debug)]
+pub struct Grouped<const SIZE: usize> {
+    value: Uint<SIZE>,
+    separator: char,
+}
+
+impl<const SIZE: usize> fmt::Display for Grouped<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = DigitBuf::new();
+        let digits = self.value.encode_decimal(&mut buf);
+        let len = digits.len() + (digits.len() - 1) / 3;
+
+        let post = write_pre_padding(f, true, "", len)?;
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                f.write_char(self.separator)?;
+            }
+            f.write_char(digit)?;
+        }
+        post.write(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uint;
+
+    #[test]
+    fn fmt_against_u128() {
+        fn check(a: u128) {
+            let my_a = Uint::<5>::from_u128(a);
+            assert_eq!(format!("{}", my_a), format!("{}", a));
+            assert_eq!(format!("{:b}", my_a), format!("{:b}", a));
+            assert_eq!(format!("{:o}", my_a), format!("{:o}", a));
+            assert_eq!(format!("{:x}", my_a), format!("{:x}", a));
+            assert_eq!(format!("{:X}", my_a), format!("{:X}", a));
+            assert_eq!(format!("{:e}", my_a), format!("{:e}", a));
+            assert_eq!(format!("{:E}", my_a), format!("{:E}", a));
+            assert_eq!(format!("{:#x}", my_a), format!("{:#x}", a));
+            assert_eq!(format!("{:>45}", my_a), format!("{:>45}", a));
+            assert_eq!(format!("{:*^45}", my_a), format!("{:*^45}", a));
+            assert_eq!(format!("{:+045}", my_a), format!("{:+045}", a));
+            assert_eq!(format!("{:<#50b}", my_a), format!("{:<#50b}", a));
+            assert_eq!(format!("{:#040o}", my_a), format!("{:#040o}", a));
+            for precision in [0, 1, 2, 5, 40, 45] {
+                assert_eq!(
+                    format!("{:.*e}", precision, my_a),
+                    format!("{:.*e}", precision, a)
+                );
+            }
+            assert_eq!(format!("{:>+30.3e}", my_a), format!("{:>+30.3e}", a));
+        }
+
+        check(0);
+        check(1);
+        check(125);
+        check(135);
+        check(99_999);
+        check(u128::MAX);
+        for _ in 0..1_000 {
+            check(rand::random::<u128>() >> (rand::random::<u32>() % 128));
+        }
+    }
+
+    #[test]
+    fn big() {
+        let v = Uint::<100>::new([u32::MAX; 100]);
+        assert_eq!(format!("{:x}", v), "ff".repeat(4 * 100));
+        assert_eq!(format!("{:o}", v), format!("3{}", "7".repeat(1066)));
+        assert_eq!(format!("{:b}", v), "1".repeat(3200));
+        assert_eq!(format!("{:.2e}", v), "1.98e963");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Uint::<3>::ZERO), "0x0");
+        assert_eq!(
+            format!("{:?}", Uint::<3>::new([0, 1, 0xabc])),
+            "0x100000abc"
+        );
+    }
+
+    #[test]
+    fn grouped() {
+        let v = Uint::<4>::from_u128(1_234_567_890);
+        assert_eq!(format!("{}", v.grouped('_')), "1_234_567_890");
+        assert_eq!(format!("{}", Uint::<1>::from_u32(123).grouped(',')), "123");
+        assert_eq!(
+            format!("{}", Uint::<1>::from_u32(1234).grouped(',')),
+            "1,234"
+        );
+        assert_eq!(format!("{}", Uint::<1>::ZERO.grouped(',')), "0");
+        assert_eq!(format!("{:<15}|", v.grouped(' ')), "1 234 567 890  |");
+        assert_eq!(format!("{:+}", v.grouped('\'')), "+1'234'567'890");
+    }
+}
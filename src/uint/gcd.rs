@@ -1,6 +1,7 @@
-use std::mem::swap;
+use core::borrow::Borrow;
+use core::mem::swap;
 
-use crate::Uint;
+use crate::{Int, Sign, Uint};
 
 impl<const S: usize> Uint<S>
 where
@@ -26,47 +27,124 @@ where
 
         let i = u.trailing_zeros();
         let j = v.trailing_zeros();
-        u = u >> i as u64;
-        v = v >> j as u64;
+        u >>= i as u64;
+        v >>= j as u64;
         let k = i.min(j);
 
         loop {
             // u and v are odd at the start of the loop
-            debug_assert!(u % Uint::TWO == Uint::ONE, "u = {} is even", u);
-            debug_assert!(v % Uint::TWO == Uint::ONE, "v = {} is even", v);
-    
+            debug_assert!(u.is_odd(), "u = {} is even", u);
+            debug_assert!(v.is_odd(), "v = {} is even", v);
+
             // Swap if necessary so u <= v
             if u > v {
                 swap(&mut u, &mut v);
             }
             // u and v are still both odd after (potentially) swapping
-    
+
             // Using identity 4 (gcd(u, v) = gcd(|v-u|, min(u, v))
             v -= u;
             // v is now even, but u is unchanged (and odd)
-    
+
             // Identity 1: gcd(u, 0) = u
             // The shift by k is necessary to add back the 2ᵏ factor that was removed before the loop
             if v == Self::ZERO {
                 return u << k as u64;
             }
-    
+
             // Identity 3: gcd(u, 2ʲ v) = gcd(u, v) (u is known to be odd)
-            v = v >> v.trailing_zeros() as u64;
+            v >>= v.trailing_zeros() as u64;
             // v is now odd again
         }
     }
 
+    /// Calculates the greatest common divisor of all numbers in `values`.
+    /// Returns zero if `values` is empty.
+    pub fn gcd_all<T: Borrow<Self>>(values: impl IntoIterator<Item = T>) -> Self {
+        let mut gcd = Self::ZERO;
+        for v in values {
+            gcd = gcd.gcd_binary(*v.borrow());
+            // the gcd can't get any smaller from here on
+            if gcd == Self::ONE {
+                break;
+            }
+        }
+        gcd
+    }
+
+    /// Calculates the greatest common divisor `g` of `self` and `other`, together with the
+    /// Bézout coefficients `x` and `y`, such that `self * x + other * y = g`.
+    ///
+    /// The coefficients are the ones found by the extended Euclidean algorithm, which are at most
+    /// `max(other / 2g, 1)` and `max(self / 2g, 1)` in magnitude, so they always fit into an `Int<S>`.
+    pub fn extended_gcd(self, other: Self) -> (Self, Int<S>, Int<S>) {
+        // The coefficients calculated in each step alternate in sign, so only their magnitudes are
+        // tracked here. This way, they are bounded by `other / g` and `self / g` and never overflow.
+        let (mut r0, mut r1) = (self, other);
+        let (mut s0, mut s1) = (Self::ONE, Self::ZERO);
+        let (mut t0, mut t1) = (Self::ZERO, Self::ONE);
+        // `t0` always has the opposite sign
+        let mut s0_negative = false;
+
+        while r1 != Self::ZERO {
+            let (q, r) = r0.div_rem(r1);
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s0 + q * s1);
+            (t0, t1) = (t1, t0 + q * t1);
+            s0_negative = !s0_negative;
+        }
 
-    #[inline(never)]
+        let (s_sign, t_sign) = match s0_negative {
+            true => (Sign::Neg, Sign::Pos),
+            false => (Sign::Pos, Sign::Neg),
+        };
+        let x = Int::from_sign_magnitude(s_sign, s0).unwrap();
+        let y = Int::from_sign_magnitude(t_sign, t0).unwrap();
+        (r0, x, y)
+    }
+
+    /// Calculates the modular multiplicative inverse of `self` modulo `modulus`, which is the number
+    /// `x < modulus` with `self * x ≡ 1 (mod modulus)`.
+    /// Returns `None` if `modulus` is zero, or if `self` and `modulus` are not coprime.
+    pub fn mod_inverse(self, modulus: Self) -> Option<Self> {
+        if modulus == Self::ZERO {
+            return None;
+        }
+        let (gcd, x, _) = (self % modulus).extended_gcd(modulus);
+        if gcd != Self::ONE {
+            return None;
+        }
+        match x.to_sign_magnitude() {
+            (Sign::Neg, x) if x != Self::ZERO => Some(modulus - x),
+            (_, x) => Some(x),
+        }
+    }
+
+    /// Calculates the least common multiple of `self` and `other`.
+    ///
+    /// `self` is divided by the gcd before multiplying, so this only overflows if the result
+    /// does not fit into `S` digits.
     pub fn lcm(self, other: Self) -> Self {
-        (self * other) / self.gcd_euclidean(other)
+        if self == Self::ZERO || other == Self::ZERO {
+            return Self::ZERO;
+        }
+        (self / self.gcd_binary(other)) * other
+    }
+
+    /// Checked least common multiple. Returns `None` if the result does not fit into `S` digits.
+    pub fn checked_lcm(self, other: Self) -> Option<Self> {
+        if self == Self::ZERO || other == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        (self / self.gcd_binary(other)).checked_mul(other)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use num_bigint::{BigInt, BigUint};
     use num_integer::Integer;
+    use num_traits::Signed;
 
     use crate::Uint;
 
@@ -92,4 +170,151 @@ mod tests {
             check(rand::random(), rand::random::<u64>() as u128);
         }
     }
+
+    #[test]
+    fn gcd_binary() {
+        for _ in 0..1_000 {
+            let (a, b) = (
+                Uint::<4>::new(rand::random()).resized::<8>(),
+                Uint::<4>::new(rand::random()).resized::<8>(),
+            );
+            assert_eq!(a.gcd_binary(b), BigUint::from(a).gcd(&BigUint::from(b)));
+            let c = Uint::<8>::from_u64(rand::random());
+            assert_eq!((a * c).gcd_binary(b * c), a.gcd_euclidean(b) * c);
+        }
+    }
+
+    #[test]
+    fn gcd_all() {
+        let values = [12u32, 18, 30].map(Uint::<2>::from_u32);
+        assert_eq!(Uint::gcd_all(values.iter()), Uint::from_u32(6));
+        assert_eq!(Uint::gcd_all(values), Uint::from_u32(6));
+        assert_eq!(
+            Uint::gcd_all(values.iter().map(|v| *v * 7u32)),
+            Uint::from_u32(42)
+        );
+        assert_eq!(Uint::<2>::gcd_all([Uint::<2>::ZERO; 0]), Uint::ZERO);
+        assert_eq!(
+            Uint::gcd_all([Uint::<2>::ZERO, Uint::from_u32(5)]),
+            Uint::from_u32(5)
+        );
+    }
+
+    #[test]
+    fn extended_gcd() {
+        fn check<const S: usize>(a: Uint<S>, b: Uint<S>)
+        where
+            [(); S + 1]:,
+        {
+            let (g, x, y) = a.extended_gcd(b);
+            let (big_a, big_b) = (
+                BigInt::from(BigUint::from(a)),
+                BigInt::from(BigUint::from(b)),
+            );
+            assert_eq!(g, BigUint::from(a).gcd(&BigUint::from(b)));
+            assert_eq!(
+                &big_a * BigInt::from(x) + &big_b * BigInt::from(y),
+                BigInt::from(BigUint::from(g))
+            );
+            if g != Uint::ZERO {
+                let double_g = BigInt::from(BigUint::from(g)) * 2u32;
+                let bound = |v: &BigInt| (v / &double_g).max(BigInt::from(1u32));
+                assert!(BigInt::from(x).abs() <= bound(&big_b));
+                assert!(BigInt::from(y).abs() <= bound(&big_a));
+            }
+        }
+
+        check(Uint::<1>::ZERO, Uint::ZERO);
+        check(Uint::<1>::ZERO, Uint::from_u32(5));
+        check(Uint::<1>::from_u32(5), Uint::ZERO);
+        check(Uint::<1>::from_u32(3), Uint::from_u32(6));
+        check(Uint::<1>::MAX, Uint::ONE);
+        check(Uint::<1>::MAX, Uint::from_u32(2));
+        check(Uint::<1>::MAX, Uint::MAX - Uint::ONE);
+        check(Uint::<4>::MAX, Uint::<4>::MAX);
+        for _ in 0..1_000 {
+            check(
+                Uint::<4>::new(rand::random()),
+                Uint::<4>::new(rand::random()),
+            );
+            check(
+                Uint::<4>::from_u64(rand::random()),
+                Uint::<4>::new(rand::random()),
+            );
+            let c = Uint::<4>::from_u32(rand::random());
+            check(
+                Uint::<4>::from_u64(rand::random()) * c,
+                Uint::<4>::from_u64(rand::random()) * c,
+            );
+        }
+    }
+
+    #[test]
+    fn mod_inverse() {
+        fn check(a: Uint<4>, m: Uint<4>) {
+            let gcd = BigUint::from(a).gcd(&BigUint::from(m));
+            match a.mod_inverse(m) {
+                Some(x) => {
+                    assert!(x < m);
+                    assert_eq!(
+                        (BigUint::from(a) * BigUint::from(x)) % BigUint::from(m),
+                        BigUint::from(1u32) % BigUint::from(m)
+                    );
+                }
+                None => assert!(m == Uint::ZERO || gcd != BigUint::from(1u32)),
+            }
+        }
+
+        assert_eq!(
+            Uint::<1>::from_u32(3).mod_inverse(Uint::from_u32(7)),
+            Some(Uint::from_u32(5))
+        );
+        assert_eq!(
+            Uint::<1>::from_u32(10).mod_inverse(Uint::from_u32(7)),
+            Some(Uint::from_u32(5))
+        );
+        assert_eq!(Uint::<1>::from_u32(2).mod_inverse(Uint::from_u32(4)), None);
+        assert_eq!(Uint::<1>::from_u32(2).mod_inverse(Uint::ZERO), None);
+        assert_eq!(
+            Uint::<1>::from_u32(2).mod_inverse(Uint::ONE),
+            Some(Uint::ZERO)
+        );
+        for _ in 0..1_000 {
+            check(Uint::new(rand::random()), Uint::new(rand::random()));
+            check(
+                Uint::new(rand::random()),
+                Uint::from_u64(rand::random::<u64>() | 1),
+            );
+        }
+    }
+
+    #[test]
+    fn lcm() {
+        fn check(a: u64, b: u64) {
+            let expected = (a as u128).lcm(&(b as u128));
+            let (my_a, my_b) = (Uint::<2>::from_u64(a), Uint::<2>::from_u64(b));
+            assert_eq!(
+                my_a.checked_lcm(my_b).map(|v| v.to_u128().unwrap()),
+                u64::try_from(expected).ok().map(u128::from)
+            );
+            assert_eq!(
+                Uint::<4>::from_u64(a).lcm(Uint::from_u64(b)).to_u128(),
+                Some(expected)
+            );
+        }
+
+        check(0, 0);
+        check(0, 5);
+        check(4, 6);
+        check(u64::MAX, u64::MAX);
+        check(u64::MAX, 2);
+        check(1 << 63, 1 << 62);
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(
+                rand::random::<u32>() as u64 * 1000,
+                rand::random::<u32>() as u64 * 1000,
+            );
+        }
+    }
 }
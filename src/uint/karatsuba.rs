@@ -0,0 +1,103 @@
+//! Multiplication on little-endian digit slices.
+//!
+//! Unlike `Uint`, all slices in here store their digits in little-endian order - `a[0]` is the LSD.
+//! This makes splitting numbers into a low and a high half for Karatsuba straightforward.
+
+use core::cmp::Ordering;
+
+use super::slice::{add_in_place, cmp_padded, sub_in_place};
+
+/// Below this many digits, schoolbook multiplication is used instead of Karatsuba.
+/// This value is a guess based on typical crossovers of other implementations - it has not been
+/// benchmarked for this crate yet.
+pub(super) const KARATSUBA_THRESHOLD: usize = 48;
+
+/// Calculates `out = a * b`.
+/// `a` and `b` must have the same length `n`, `out` must have the length `2n`.
+///
+/// `scratch` is used as temporary storage, and must hold at least `6n` digits if `n` is at least
+/// `KARATSUBA_THRESHOLD`.
+pub(super) fn mul(out: &mut [u32], a: &[u32], b: &[u32], scratch: &mut [u32]) {
+    let n = a.len();
+    debug_assert_eq!(b.len(), n);
+    debug_assert_eq!(out.len(), 2 * n);
+
+    if n < KARATSUBA_THRESHOLD {
+        schoolbook(out, a, b);
+        return;
+    }
+
+    // a = a1 * B^m + a0
+    // b = b1 * B^m + b0
+    let m = n / 2;
+    let h = n - m;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    // z0 = a0 * b0 and z2 = a1 * b1 go straight into the low and high part of the output
+    {
+        let (lo, hi) = out.split_at_mut(2 * m);
+        mul(lo, a0, b0, scratch);
+        mul(hi, a1, b1, scratch);
+    }
+
+    // z1 = a1 * b0 + a0 * b1 = z2 + z0 - (a1 - a0)(b1 - b0)
+    let (prod, rest) = scratch.split_at_mut(2 * h);
+    let (z1, rest) = rest.split_at_mut(2 * h + 1);
+
+    // |a1 - a0| and |b1 - b0| are stored in `z1` until their product is calculated
+    let prod_negative = {
+        let (da, db) = z1.split_at_mut(h);
+        let a_negative = abs_diff(da, a1, a0);
+        let b_negative = abs_diff(&mut db[..h], b1, b0);
+        mul(prod, da, &db[..h], rest);
+        a_negative != b_negative
+    };
+
+    z1.fill(0);
+    z1[..2 * m].copy_from_slice(&out[..2 * m]);
+    add_in_place(z1, &out[2 * m..]);
+    if prod_negative {
+        add_in_place(z1, prod);
+    } else {
+        let borrow = sub_in_place(z1, prod);
+        debug_assert!(!borrow);
+    }
+
+    let carry = add_in_place(&mut out[m..], z1);
+    debug_assert!(!carry);
+}
+
+/// Calculates `out = a * b` using schoolbook multiplication.
+/// `out` must have the length `a.len() + b.len()`.
+pub(super) fn schoolbook(out: &mut [u32], a: &[u32], b: &[u32]) {
+    debug_assert_eq!(out.len(), a.len() + b.len());
+    out.fill(0);
+    for (j, b) in b.iter().enumerate() {
+        let b = *b as u64;
+        if b == 0 {
+            continue;
+        }
+        let mut carry = 0u64;
+        for (i, a) in a.iter().enumerate() {
+            let t = *a as u64 * b + out[i + j] as u64 + carry;
+            out[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        out[a.len() + j] = carry as u32;
+    }
+}
+
+// calculates `out = |x - y|`, returning `true` if `y > x`.
+// `y` may be shorter than `x`, in which case it is treated as if it was padded with 0s.
+fn abs_diff(out: &mut [u32], x: &[u32], y: &[u32]) -> bool {
+    debug_assert_eq!(out.len(), x.len());
+    let negative = cmp_padded(x, y) == Ordering::Less;
+    let (larger, smaller) = if negative { (y, x) } else { (x, y) };
+
+    out.fill(0);
+    out[..larger.len()].copy_from_slice(larger);
+    let borrow = sub_in_place(out, smaller);
+    debug_assert!(!borrow);
+    negative
+}
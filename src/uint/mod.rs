@@ -1,14 +1,42 @@
-use std::fmt::Debug;
-use std::ops::{Index, IndexMut, Shl, Shr};
+use core::fmt::Debug;
+use core::ops::{Index, IndexMut, Shl, Shr};
 
 mod add;
 mod base;
+#[cfg(any(test, feature = "num-bigint"))]
+mod bigint;
+mod bit;
+mod const_ops;
 mod convert;
+mod ct;
 mod div;
+mod float;
+mod format;
 mod gcd;
+mod karatsuba;
 mod mul;
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg(feature = "num-traits")]
+mod num;
+mod parse;
+mod pow;
+mod prime;
+mod radix;
+#[cfg(feature = "rand")]
+mod random;
+mod root;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+
+pub(crate) use convert::AssertMinSize;
+pub use ct::Choice;
+pub(crate) use format::{write_pre_padding, DigitBuf};
+pub use format::Grouped;
+pub use parse::ParseUintError;
+#[cfg(feature = "rand")]
+pub use random::UniformUint;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Uint<const SIZE: usize> {
     // digits in big-endian order - digits[0] is the MSB
     digits: [u32; SIZE],
@@ -22,6 +50,8 @@ impl<const SIZE: usize> Uint<SIZE> {
         digits: [u32::MAX; SIZE],
     };
     pub const SIZE_TWICE: usize = SIZE * 2;
+    /// The size of this integer type in bits.
+    pub const BITS: u32 = SIZE as u32 * 32;
 
     pub const fn new(digits: [u32; SIZE]) -> Self {
         Uint { digits }
@@ -95,6 +125,50 @@ impl<const SIZE: usize> Uint<SIZE> {
         }
         result
     }
+
+    /// Shifts `self` left by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of `self` along with a boolean indicating whether the
+    /// shift value was larger than or equal to the number of bits. If the shift value is too large,
+    /// then it is masked (`rhs % BITS`) and this value is used to perform the shift.
+    pub fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        (self << (rhs % Self::BITS) as u64, rhs >= Self::BITS)
+    }
+
+    /// Checked shift left. Computes `self << rhs`, returning `None` if `rhs >= BITS`.
+    pub fn checked_shl(self, rhs: u32) -> Option<Self> {
+        match self.overflowing_shl(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Panic-free bitwise shift-left; yields `self << (rhs % BITS)`.
+    pub fn wrapping_shl(self, rhs: u32) -> Self {
+        self.overflowing_shl(rhs).0
+    }
+
+    /// Shifts `self` right by `rhs` bits.
+    ///
+    /// Returns a tuple of the shifted version of `self` along with a boolean indicating whether the
+    /// shift value was larger than or equal to the number of bits. If the shift value is too large,
+    /// then it is masked (`rhs % BITS`) and this value is used to perform the shift.
+    pub fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (self >> (rhs % Self::BITS) as u64, rhs >= Self::BITS)
+    }
+
+    /// Checked shift right. Computes `self >> rhs`, returning `None` if `rhs >= BITS`.
+    pub fn checked_shr(self, rhs: u32) -> Option<Self> {
+        match self.overflowing_shr(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Panic-free bitwise shift-right; yields `self >> (rhs % BITS)`.
+    pub fn wrapping_shr(self, rhs: u32) -> Self {
+        self.overflowing_shr(rhs).0
+    }
 }
 
 impl<const SIZE: usize> Shl<u64> for Uint<SIZE> {
@@ -183,6 +257,22 @@ mod tests {
             3 * 32 + 1
         );
     }
+
+    #[test]
+    fn checked_shifts() {
+        fn check(a: u128, rhs: u32) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.checked_shl(rhs).map(|r| r.to_u128().unwrap()), a.checked_shl(rhs));
+            assert_eq!(my_a.checked_shr(rhs).map(|r| r.to_u128().unwrap()), a.checked_shr(rhs));
+            assert_eq!(my_a.wrapping_shl(rhs).to_u128().unwrap(), a.wrapping_shl(rhs));
+            assert_eq!(my_a.wrapping_shr(rhs).to_u128().unwrap(), a.wrapping_shr(rhs));
+        }
+
+        for rhs in [0, 1, 31, 32, 33, 64, 100, 127, 128, 129, 300] {
+            check(u128::MAX, rhs);
+            check(0x1234_5678_9abc_def0_0fed_cba9_8765_4321, rhs);
+        }
+    }
 }
 
 /// an array holding 2*N elements of type T
@@ -196,6 +286,12 @@ impl<const N: usize, T: Copy> Array2N<N, T> {
     }
 }
 
+impl<const N: usize, T> Array2N<N, T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_flattened_mut()
+    }
+}
+
 impl<const N: usize, T> Index<usize> for Array2N<N, T> {
     type Output = T;
 
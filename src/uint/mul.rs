@@ -1,12 +1,141 @@
-use crate::uint::Uint;
-use std::ops::{Mul, MulAssign};
+use crate::uint::karatsuba::{self, KARATSUBA_THRESHOLD};
+use crate::uint::{Array2N, Uint};
+use core::ops::{Mul, MulAssign};
 
-impl<const SIZE: usize> Uint<SIZE> {}
+struct AssertFullProduct<const SIZE: usize, const OUT: usize>;
+
+impl<const SIZE: usize, const OUT: usize> AssertFullProduct<SIZE, OUT> {
+    const OK: () = assert!(
+        OUT >= 2 * SIZE,
+        "the output is too small to hold the full product"
+    );
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Calculates `self * rhs` for a single digit `rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_mul_u32(mut self, rhs: u32) -> (Self, bool) {
+        let mut carry = 0;
+        for j in (0..SIZE).rev() {
+            let (result, c) = self.digits[j].carrying_mul(rhs, carry);
+            carry = c;
+            self.digits[j] = result;
+        }
+        (self, carry != 0)
+    }
+
+    /// Checked multiplication by a single digit. Returns `None` if overflow occurred.
+    pub fn checked_mul_u32(self, rhs: u32) -> Option<Self> {
+        match self.overflowing_mul_u32(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) multiplication by a single digit.
+    pub fn wrapping_mul_u32(self, rhs: u32) -> Self {
+        self.overflowing_mul_u32(rhs).0
+    }
+
+    /// Calculates `self * rhs`.
+    ///
+    /// Returns a tuple of the multiplication along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        if self.significant_digits().max(rhs.significant_digits()) >= KARATSUBA_THRESHOLD {
+            let (lo, hi) = self.widening_mul(rhs);
+            return (lo, hi != Self::ZERO);
+        }
+
+        let mut out = Self::ZERO;
+        let mut overflow = false;
+
+        // `i` and `j` index the digits starting from the LSD, so digit `i` of `self` times digit
+        // `j` of `rhs` lands at `i + j`. Everything landing at `SIZE` or above is lost.
+        for j in 0..SIZE {
+            let b = rhs.digits[SIZE - 1 - j] as u64;
+            if b == 0 {
+                continue;
+            }
+            let mut carry = 0u64;
+            for i in 0..SIZE - j {
+                let idx = SIZE - 1 - (i + j);
+                let t = self.digits[SIZE - 1 - i] as u64 * b + out.digits[idx] as u64 + carry;
+                out.digits[idx] = t as u32;
+                carry = t >> 32;
+            }
+            overflow |= carry != 0 || self.digits[..j].iter().any(|d| *d != 0);
+        }
+
+        (out, overflow)
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at the boundary of the type.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Calculates the complete product `self * rhs` without the possibility to overflow.
+    ///
+    /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+    /// of the result as two separate values, in that order.
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        let product = self.full_product(rhs);
+        let (mut lo, mut hi) = (Self::ZERO, Self::ZERO);
+        for i in 0..SIZE {
+            lo.digits[SIZE - 1 - i] = product[i];
+            hi.digits[SIZE - 1 - i] = product[SIZE + i];
+        }
+        (lo, hi)
+    }
+
+    /// Calculates the complete product `self * rhs`, returning it as a `Uint<OUT>`.
+    /// Usually, `OUT` will be `2 * SIZE`, in which case the product always fits.
+    /// An `OUT` smaller than `2 * SIZE` is rejected at compile time.
+    pub fn full_mul<const OUT: usize>(self, rhs: Self) -> Uint<OUT> {
+        let () = AssertFullProduct::<SIZE, OUT>::OK;
+        let product = self.full_product(rhs);
+        let mut out = Uint::<OUT>::ZERO;
+        for i in 0..2 * SIZE {
+            out.digits[OUT - 1 - i] = product[i];
+        }
+        out
+    }
+
+    // multiplies `self` and `rhs`, writing all 2 * SIZE digits of the product.
+    // the digits of the result are in little-endian order - product[0] is the LSD.
+    fn full_product(self, rhs: Self) -> Array2N<SIZE, u32> {
+        let mut product = Array2N::new(0u32);
+
+        // only the significant digits of the operands need to be multiplied
+        let n = self.significant_digits().max(rhs.significant_digits());
+        let (a, b) = (self.to_limbs_le(), rhs.to_limbs_le());
+        let out = &mut product.as_mut_slice()[..2 * n];
+        if n < KARATSUBA_THRESHOLD {
+            karatsuba::schoolbook(out, &a[..n], &b[..n]);
+        } else {
+            let mut scratch = [[0u32; SIZE]; 6];
+            karatsuba::mul(out, &a[..n], &b[..n], scratch.as_flattened_mut());
+        }
+
+        product
+    }
+}
 
 impl<const SIZE: usize> Mul<u32> for Uint<SIZE> {
     type Output = Self;
 
-    fn mul(mut self, rhs: u32) -> Self::Output {
+    fn mul(self, rhs: u32) -> Self::Output {
         // needs some benchmarking when if makes sense to do this check
         if SIZE >= 64 {
             match rhs {
@@ -16,17 +145,9 @@ impl<const SIZE: usize> Mul<u32> for Uint<SIZE> {
             };
         }
 
-        let mut carry = 0;
-        for j in (0..SIZE).rev() {
-            let (result, c) = self.digits[j].carrying_mul(rhs, carry);
-            carry = c;
-            self.digits[j] = result;
-        }
-        if carry != 0 {
-            println!("{:?} * {:?}", self, rhs);
-            assert_eq!(carry, 0, "attempt to multiply with overflow");
-        }
-        self
+        let (res, overflow) = self.overflowing_mul_u32(rhs);
+        assert!(!overflow, "attempt to multiply with overflow");
+        res
     }
 }
 
@@ -40,16 +161,9 @@ impl<const SIZE: usize> Mul for Uint<SIZE> {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut out = Self::ZERO;
-
-        for j in (0..SIZE).rev() {
-            let digit = rhs.digits[j];
-            let mut row = self * digit;
-            row.shl_digits(SIZE - 1 - j);
-            out += row;
-        }
-
-        out
+        let (res, overflow) = self.overflowing_mul(rhs);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        res
     }
 }
 
@@ -64,6 +178,7 @@ mod tests {
     use num_bigint::BigUint;
     use rand::Rng;
 
+    use crate::uint::karatsuba::KARATSUBA_THRESHOLD;
     use crate::uint::Uint;
 
     #[test]
@@ -94,6 +209,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn karatsuba_against_num_bigint() {
+        for _ in 0..100 {
+            fuzz::<48>();
+            fuzz::<49>();
+            fuzz::<64>();
+            fuzz::<97>();
+            fuzz::<128>();
+        }
+    }
+
+    #[test]
+    fn karatsuba_threshold() {
+        // operands with a number of significant digits right around the threshold
+        for n in KARATSUBA_THRESHOLD - 2..=KARATSUBA_THRESHOLD + 2 {
+            for _ in 0..20 {
+                fuzz_significant::<128>(n, n);
+                fuzz_significant::<128>(n, 1);
+                fuzz_significant::<128>(2 * n, n / 2);
+            }
+        }
+        check_correct(Uint::<64>::MAX.resized::<128>(), Uint::<64>::MAX.resized());
+    }
+
+    fn fuzz_significant<const S: usize>(a_digits: usize, b_digits: usize) {
+        let mut a = [0u32; S];
+        let mut b = [0u32; S];
+        rand::thread_rng().fill(&mut a[S - a_digits..]);
+        rand::thread_rng().fill(&mut b[S - b_digits..]);
+        check_correct(Uint::new(a), Uint::new(b));
+    }
+
     fn check_correct<const S: usize>(a: Uint<S>, b: Uint<S>) {
         let my_res = a * b;
         let c_res = BigUint::from(a) * BigUint::from(b);
@@ -120,4 +267,53 @@ mod tests {
 
         check_correct::<{ A * 2 }>(my_a, my_b);
     }
+
+    #[test]
+    fn widening_against_num_bigint() {
+        fn check<const S: usize>(a: Uint<S>, b: Uint<S>) {
+            let c_res = BigUint::from(a) * BigUint::from(b);
+            let (lo, hi) = a.widening_mul(b);
+            assert_eq!(BigUint::from(hi) << (S * 32) | BigUint::from(lo), c_res);
+            assert_eq!(a.full_mul::<16>(b), c_res);
+        }
+
+        check(Uint::<8>::MAX, Uint::MAX);
+        check(Uint::<8>::ZERO, Uint::MAX);
+        check(Uint::<1>::MAX, Uint::MAX);
+        for _ in 0..1_000 {
+            let mut a = [0u32; 8];
+            let mut b = [0u32; 8];
+            rand::thread_rng().fill(&mut a[..]);
+            rand::thread_rng().fill(&mut b[..]);
+            check(Uint::new(a), Uint::new(b));
+            check(Uint::new([a[0], a[1], a[2]]), Uint::new([b[0], b[1], b[2]]));
+        }
+    }
+
+    #[test]
+    fn overflowing_against_u128() {
+        fn check(a: u128, b: u128) {
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+
+            let (res, o) = my_a.overflowing_mul(my_b);
+            assert_eq!((res.to_u128().unwrap(), o), a.overflowing_mul(b));
+            assert_eq!(my_a.checked_mul(my_b).map(|r| r.to_u128().unwrap()), a.checked_mul(b));
+            assert_eq!(my_a.wrapping_mul(my_b).to_u128().unwrap(), a.wrapping_mul(b));
+
+            let d = b as u32;
+            let (res, o) = my_a.overflowing_mul_u32(d);
+            assert_eq!((res.to_u128().unwrap(), o), a.overflowing_mul(d as u128));
+        }
+
+        check(0, u128::MAX);
+        check(u128::MAX, 1);
+        check(u128::MAX, 2);
+        check(1 << 64, 1 << 63);
+        check(1 << 64, 1 << 64);
+        for _ in 0..1_000 {
+            check(rand::random(), rand::random());
+            check(rand::random::<u64>() as u128, rand::random::<u64>() as u128);
+            check(rand::random::<u32>() as u128, rand::random());
+        }
+    }
 }
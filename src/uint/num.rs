@@ -0,0 +1,255 @@
+use core::any::Any;
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, Pow,
+    ToPrimitive, Unsigned, WrappingAdd, Zero,
+};
+
+use crate::uint::{ParseUintError, Uint};
+
+impl<const SIZE: usize> Zero for Uint<SIZE> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl<const SIZE: usize> One for Uint<SIZE> {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+}
+
+impl<const SIZE: usize> Num for Uint<SIZE>
+where
+    [(); SIZE + 1]: Any,
+{
+    type FromStrRadixErr = ParseUintError;
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseUintError> {
+        Uint::from_str_radix(src, radix)
+    }
+}
+
+impl<const SIZE: usize> Unsigned for Uint<SIZE> where [(); SIZE + 1]: Any {}
+
+impl<const SIZE: usize> Bounded for Uint<SIZE> {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const SIZE: usize> CheckedAdd for Uint<SIZE> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Uint::checked_add(*self, *v)
+    }
+}
+
+impl<const SIZE: usize> CheckedSub for Uint<SIZE> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Uint::checked_sub(*self, *v)
+    }
+}
+
+impl<const SIZE: usize> CheckedMul for Uint<SIZE> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Uint::checked_mul(*self, *v)
+    }
+}
+
+impl<const SIZE: usize> CheckedDiv for Uint<SIZE>
+where
+    [(); SIZE + 1]: Any,
+{
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Uint::checked_div(*self, *v)
+    }
+}
+
+impl<const SIZE: usize> WrappingAdd for Uint<SIZE> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Uint::wrapping_add(*self, *v)
+    }
+}
+
+impl<const SIZE: usize> Pow<u32> for Uint<SIZE> {
+    type Output = Self;
+
+    fn pow(self, exp: u32) -> Self {
+        Uint::pow(self, exp)
+    }
+}
+
+impl<const SIZE: usize> ToPrimitive for Uint<SIZE> {
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(*self).ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        Uint::to_u128(*self)
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(Uint::to_f32(self))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(Uint::to_f64(self))
+    }
+}
+
+impl<const SIZE: usize> FromPrimitive for Uint<SIZE> {
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().and_then(Self::try_from_u64)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::try_from_u64(n)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        u128::try_from(n).ok().and_then(Self::try_from_u128)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from_u128(n)
+    }
+
+    /// Converts a float to a `Uint`, rounding towards zero.
+    /// Returns `None` for negative, infinite or NaN values and values larger than `Uint::MAX`.
+    fn from_f32(n: f32) -> Option<Self> {
+        Uint::from_f32(n)
+    }
+
+    /// Converts a float to a `Uint`, rounding towards zero.
+    /// Returns `None` for negative, infinite or NaN values and values larger than `Uint::MAX`.
+    fn from_f64(n: f64) -> Option<Self> {
+        Uint::from_f64(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use num_traits::{
+        Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Num, One, Pow,
+        ToPrimitive, Unsigned, WrappingAdd, Zero,
+    };
+
+    use crate::Uint;
+
+    #[test]
+    fn generic_ops() {
+        // only uses the traits, like generic code would
+        fn sum_of_powers<T: Num + Pow<u32, Output = T> + Copy>(values: &[T], exp: u32) -> T {
+            values.iter().fold(T::zero(), |acc, v| acc + v.pow(exp))
+        }
+        fn is_unsigned<T: Unsigned>() {}
+        is_unsigned::<Uint<4>>();
+
+        let values = [3u128, 5, 1 << 20];
+        let mine: Vec<_> = values.iter().map(|v| Uint::<4>::from_u128(*v)).collect();
+        assert_eq!(
+            sum_of_powers(&mine, 3),
+            Uint::from_u128(sum_of_powers(&values, 3))
+        );
+        assert_eq!(
+            num_traits::pow(Uint::<4>::from_u32(3), 80),
+            BigUint::from(3u32).pow(80u32)
+        );
+
+        assert!(Uint::<2>::zero().is_zero());
+        assert!(Uint::<2>::one().is_one());
+        assert_eq!(Uint::<2>::max_value(), Uint::MAX);
+        assert_eq!(Uint::<2>::min_value(), Uint::ZERO);
+        assert_eq!(
+            <Uint<4> as Num>::from_str_radix("ff_ff", 16),
+            Ok(Uint::from_u32(0xffff))
+        );
+    }
+
+    #[test]
+    fn checked_against_u128() {
+        for _ in 0..100 {
+            let (a, b) = (rand::random::<u128>() >> 1, rand::random::<u128>() >> 64);
+            let (my_a, my_b) = (Uint::<4>::from_u128(a), Uint::<4>::from_u128(b));
+            let to_u128 = |v: Option<Uint<4>>| v.map(|v| v.to_u128().unwrap());
+            assert_eq!(
+                to_u128(CheckedAdd::checked_add(&my_a, &my_b)),
+                a.checked_add(b)
+            );
+            assert_eq!(
+                to_u128(CheckedSub::checked_sub(&my_a, &my_b)),
+                a.checked_sub(b)
+            );
+            assert_eq!(
+                to_u128(CheckedMul::checked_mul(&my_a, &my_b)),
+                a.checked_mul(b)
+            );
+            assert_eq!(
+                to_u128(CheckedDiv::checked_div(&my_a, &my_b)),
+                a.checked_div(b)
+            );
+            assert_eq!(
+                WrappingAdd::wrapping_add(&(my_a << 1u64), &my_b).to_u128(),
+                Some((a << 1).wrapping_add(b))
+            );
+        }
+        assert_eq!(CheckedDiv::checked_div(&Uint::<4>::ONE, &Uint::ZERO), None);
+        assert_eq!(CheckedAdd::checked_add(&Uint::<4>::MAX, &Uint::ONE), None);
+    }
+
+    #[test]
+    fn primitives() {
+        let v = Uint::<4>::from_u128(u64::MAX as u128 + 1);
+        assert_eq!(ToPrimitive::to_u64(&v), None);
+        assert_eq!(ToPrimitive::to_u128(&v), Some(u64::MAX as u128 + 1));
+        assert_eq!(ToPrimitive::to_i128(&v), Some(u64::MAX as i128 + 1));
+        assert_eq!(ToPrimitive::to_u32(&Uint::<4>::from_u32(7)), Some(7));
+        assert_eq!(ToPrimitive::to_i8(&Uint::<4>::from_u32(128)), None);
+        assert_eq!(ToPrimitive::to_i128(&Uint::<4>::MAX), None);
+        assert_eq!(ToPrimitive::to_f64(&v), Some(2f64.powi(64)));
+
+        assert_eq!(
+            <Uint<1> as FromPrimitive>::from_u64(u32::MAX as u64),
+            Some(Uint::MAX)
+        );
+        assert_eq!(<Uint<1> as FromPrimitive>::from_u64(1 << 32), None);
+        assert_eq!(<Uint<4> as FromPrimitive>::from_i64(-1), None);
+        assert_eq!(
+            <Uint<4> as FromPrimitive>::from_i32(5),
+            Some(Uint::from_u32(5))
+        );
+        assert_eq!(
+            <Uint<4> as FromPrimitive>::from_u128(u128::MAX),
+            Some(Uint::MAX)
+        );
+        assert_eq!(<Uint<2> as FromPrimitive>::from_u128(u128::MAX), None);
+        assert_eq!(<Uint<4> as FromPrimitive>::from_i128(-1), None);
+        assert_eq!(
+            <Uint<4> as FromPrimitive>::from_f64(2.9),
+            Some(Uint::from_u32(2))
+        );
+        assert_eq!(<Uint<4> as FromPrimitive>::from_f64(-1.0), None);
+        assert_eq!(<Uint<4> as FromPrimitive>::from_f64(f64::NAN), None);
+    }
+}
@@ -0,0 +1,191 @@
+use core::fmt;
+use core::str::FromStr;
+
+use crate::uint::Uint;
+
+/// An error which can be returned when parsing a [`Uint`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ParseUintError {
+    /// The string to parse did not contain any digits.
+    Empty,
+    /// The string contained a character which is not a digit in the given radix.
+    InvalidDigit,
+    /// The number does not fit into `SIZE` digits.
+    Overflow,
+}
+
+impl fmt::Display for ParseUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ParseUintError::Empty => "cannot parse integer from empty string",
+            ParseUintError::InvalidDigit => "invalid digit found in string",
+            ParseUintError::Overflow => "number too large to fit in target type",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseUintError {}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Converts a string slice in a given base to an integer.
+    ///
+    /// The string may start with an optional `+` sign. Digits can be separated by `_`, though
+    /// the string may not start with one. Digits are `0-9`, `a-z` and `A-Z`, depending on `radix`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseUintError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]` - found {}",
+            radix
+        );
+
+        let src = match src.strip_prefix('+') {
+            Some("") => return Err(ParseUintError::InvalidDigit),
+            Some(src) => src,
+            None => src,
+        };
+        if src.starts_with('_') {
+            return Err(ParseUintError::InvalidDigit);
+        }
+        Self::parse_digits(src.as_bytes(), radix)
+    }
+
+    // parses `src` in chunks of as many digits as fit into one u32.
+    // for radix 10, that's 9 digits per multiplication.
+    fn parse_digits(src: &[u8], radix: u32) -> Result<Self, ParseUintError> {
+        let (chunk_len, chunk_base) = chunk_size(radix);
+
+        let mut result = Self::ZERO;
+        let mut chunk = 0u32;
+        let mut chunk_digits = 0;
+        let mut digits = 0;
+        for c in src {
+            if *c == b'_' {
+                continue;
+            }
+            let digit = (*c as char)
+                .to_digit(radix)
+                .ok_or(ParseUintError::InvalidDigit)?;
+            chunk = chunk * radix + digit;
+            chunk_digits += 1;
+            digits += 1;
+
+            if chunk_digits == chunk_len {
+                result = result.push_chunk(chunk_base, chunk)?;
+                chunk = 0;
+                chunk_digits = 0;
+            }
+        }
+
+        if digits == 0 {
+            return Err(ParseUintError::Empty);
+        }
+        if chunk_digits > 0 {
+            result = result.push_chunk(radix.pow(chunk_digits), chunk)?;
+        }
+        Ok(result)
+    }
+
+    // calculates `self * base + chunk`
+    fn push_chunk(self, base: u32, chunk: u32) -> Result<Self, ParseUintError> {
+        self.checked_mul_u32(base)
+            .and_then(|r| r.checked_add(Uint::from_u32(chunk)))
+            .ok_or(ParseUintError::Overflow)
+    }
+}
+
+// returns the largest number of digits `n` for which `radix^n` fits into a u32, and `radix^n`.
+fn chunk_size(radix: u32) -> (u32, u32) {
+    let mut len = 1;
+    let mut base = radix;
+    while let Some(next) = base.checked_mul(radix) {
+        len += 1;
+        base = next;
+    }
+    (len, base)
+}
+
+impl<const SIZE: usize> FromStr for Uint<SIZE> {
+    type Err = ParseUintError;
+
+    /// Parses a number from a string.
+    /// By default, the number is parsed in base 10. The prefixes `0x`, `0o` and `0b` select
+    /// base 16, 8 and 2 respectively. Digits can be separated by `_`.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let src = match src.strip_prefix('+') {
+            Some("") => return Err(ParseUintError::InvalidDigit),
+            Some(src) => src,
+            None => src,
+        };
+
+        let (src, radix) = match src.get(..2) {
+            Some("0x" | "0X") => (&src[2..], 16),
+            Some("0o" | "0O") => (&src[2..], 8),
+            Some("0b" | "0B") => (&src[2..], 2),
+            _ if src.starts_with('_') => return Err(ParseUintError::InvalidDigit),
+            _ => (src, 10),
+        };
+        Self::parse_digits(src.as_bytes(), radix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use num_traits::Num;
+    use rand::Rng;
+
+    use crate::uint::{ParseUintError, Uint};
+
+    #[test]
+    fn trivial() {
+        assert_eq!("0".parse(), Ok(Uint::<2>::ZERO));
+        assert_eq!("+1".parse(), Ok(Uint::<2>::ONE));
+        assert_eq!("18446744073709551615".parse(), Ok(Uint::<2>::MAX));
+        assert_eq!("0xffff_ffff_ffff_ffff".parse(), Ok(Uint::<2>::MAX));
+        assert_eq!("0o17".parse(), Ok(Uint::<2>::from_u32(15)));
+        assert_eq!("0b1010".parse(), Ok(Uint::<2>::from_u32(10)));
+        assert_eq!("1_000_000".parse(), Ok(Uint::<2>::from_u32(1_000_000)));
+        assert_eq!(Uint::<2>::from_str_radix("zz", 36), Ok(Uint::from_u32(36 * 36 - 1)));
+        assert_eq!(Uint::<2>::from_str_radix("0b1", 16), Ok(Uint::from_u32(0xb1)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Uint<2>>(), Err(ParseUintError::Empty));
+        assert_eq!("0x".parse::<Uint<2>>(), Err(ParseUintError::Empty));
+        assert_eq!("+".parse::<Uint<2>>(), Err(ParseUintError::InvalidDigit));
+        assert_eq!("-1".parse::<Uint<2>>(), Err(ParseUintError::InvalidDigit));
+        assert_eq!("_1".parse::<Uint<2>>(), Err(ParseUintError::InvalidDigit));
+        assert_eq!("12a".parse::<Uint<2>>(), Err(ParseUintError::InvalidDigit));
+        assert_eq!("0b2".parse::<Uint<2>>(), Err(ParseUintError::InvalidDigit));
+        assert_eq!(
+            "18446744073709551616".parse::<Uint<2>>(),
+            Err(ParseUintError::Overflow)
+        );
+        assert_eq!(
+            Uint::<1>::from_str_radix("1_0000_0000", 16),
+            Err(ParseUintError::Overflow)
+        );
+    }
+
+    #[test]
+    fn against_num_bigint() {
+        for _ in 0..500 {
+            let mut digits = [0u32; 8];
+            rand::thread_rng().fill(&mut digits[..]);
+            let n = Uint::new(digits);
+            for radix in [2, 3, 7, 8, 10, 16, 36] {
+                let s = BigUint::from(n).to_str_radix(radix);
+                assert_eq!(Uint::<8>::from_str_radix(&s, radix), Ok(n));
+                assert_eq!(BigUint::from_str_radix(&s, radix).unwrap(), n);
+            }
+            assert_eq!(n.to_string().parse(), Ok(n));
+        }
+    }
+}
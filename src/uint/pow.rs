@@ -0,0 +1,179 @@
+use crate::uint::Uint;
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// Returns a tuple of the exponentiation along with a boolean indicating whether an arithmetic
+    /// overflow would occur. If an overflow would have occurred then the wrapped value is returned.
+    pub fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        if exp == 0 {
+            return (Self::ONE, false);
+        }
+
+        let mut base = self;
+        let mut acc = Self::ONE;
+        let mut overflow = false;
+        while exp > 1 {
+            if exp & 1 == 1 {
+                let (r, o) = acc.overflowing_mul(base);
+                acc = r;
+                overflow |= o;
+            }
+            exp /= 2;
+            let (r, o) = base.overflowing_mul(base);
+            base = r;
+            overflow |= o;
+        }
+
+        // the base is not squared after the last bit, since that could overflow needlessly
+        let (r, o) = acc.overflowing_mul(base);
+        (r, overflow | o)
+    }
+
+    /// Raises `self` to the power of `exp`, using exponentiation by squaring.
+    pub fn pow(self, exp: u32) -> Self {
+        let (res, overflow) = self.overflowing_pow(exp);
+        debug_assert!(!overflow, "attempt to multiply with overflow");
+        res
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if overflow occurred.
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`, wrapping around at the
+    /// boundary of the type.
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+}
+
+impl<const S: usize> Uint<S>
+where
+    [(); S + 1]:,
+    [(); 2 * S + 1]:,
+{
+    /// Calculates `self^exp mod modulus` using sliding-window exponentiation.
+    ///
+    /// All intermediate products are calculated with twice the size, so this never overflows,
+    /// even if `modulus` fills all `S` digits.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `modulus` is zero
+    pub fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+        assert_ne!(modulus, Uint::ZERO, "attempt to calculate the remainder with a divisor of zero");
+
+        let base = self % modulus;
+        let bits = exp.bits();
+        let window = match bits {
+            0..=7 => 1,
+            8..=63 => 3,
+            64..=511 => 4,
+            _ => 5,
+        };
+
+        // odd powers of the base: table[i] = base^(2i + 1)
+        let mut table = [Self::ZERO; 16];
+        table[0] = base;
+        let base_squared = base.mul_mod(base, modulus);
+        for i in 1..1 << (window - 1) {
+            table[i] = table[i - 1].mul_mod(base_squared, modulus);
+        }
+
+        // `ONE % modulus`, since the modulus might be one
+        let mut result = Self::ONE % modulus;
+        let mut i = bits as i64 - 1;
+        while i >= 0 {
+            if !exp.bit(i as u32) {
+                result = result.mul_mod(result, modulus);
+                i -= 1;
+                continue;
+            }
+
+            // find the longest window exp[i..=l] ending with a set bit
+            let mut l = (i - window + 1).max(0);
+            while !exp.bit(l as u32) {
+                l += 1;
+            }
+
+            let mut value = 0;
+            for j in (l..=i).rev() {
+                result = result.mul_mod(result, modulus);
+                value = value << 1 | exp.bit(j as u32) as usize;
+            }
+            result = result.mul_mod(table[value / 2], modulus);
+            i = l - 1;
+        }
+
+        result
+    }
+
+    // calculates `self * rhs mod modulus` without overflowing
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self {
+        let product = self.full_mul::<{ 2 * S }>(rhs);
+        (product % modulus.resized()).resized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    use crate::Uint;
+
+    #[test]
+    fn pow_against_u128() {
+        fn check(a: u128, exp: u32) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.checked_pow(exp).map(|r| r.to_u128().unwrap()), a.checked_pow(exp));
+            assert_eq!(my_a.wrapping_pow(exp).to_u128(), Some(a.wrapping_pow(exp)));
+        }
+
+        for exp in [0, 1, 2, 3, 7, 31, 64, 127, 128, 1000] {
+            check(0, exp);
+            check(1, exp);
+            check(2, exp);
+            check(3, exp);
+            check(u128::MAX, exp);
+            check(rand::random(), exp);
+            check(rand::random::<u16>() as u128, exp);
+        }
+        assert_eq!(Uint::<4>::from_u32(10).pow(38), Uint::from_u128(10u128.pow(38)));
+    }
+
+    #[test]
+    fn pow_mod_against_num_bigint() {
+        fn check<const S: usize>(a: Uint<S>, exp: Uint<S>, modulus: Uint<S>)
+        where
+            [(); S + 1]:,
+            [(); 2 * S + 1]:,
+        {
+            let c = BigUint::from(a).modpow(&BigUint::from(exp), &BigUint::from(modulus));
+            assert_eq!(a.pow_mod(exp, modulus), c);
+        }
+
+        check(Uint::<4>::ZERO, Uint::ZERO, Uint::ONE);
+        check(Uint::<4>::ZERO, Uint::ZERO, Uint::MAX);
+        check(Uint::<4>::MAX, Uint::MAX, Uint::MAX);
+        check(Uint::<4>::MAX, Uint::MAX, Uint::MAX - Uint::ONE);
+        check(Uint::<4>::TWO, Uint::from_u32(1000), Uint::from_u32(1_000_000_007));
+
+        for _ in 0..100 {
+            let mut a = [0u32; 8];
+            let mut e = [0u32; 8];
+            let mut m = [0u32; 8];
+            rand::thread_rng().fill(&mut a[..]);
+            rand::thread_rng().fill(&mut e[..]);
+            rand::thread_rng().fill(&mut m[..]);
+            check(Uint::new(a), Uint::new(e), Uint::new(m));
+            check(Uint::new(a), Uint::from_u32(e[0]), Uint::new(m));
+            check(Uint::new(a), Uint::from_u32(e[0] % 100), Uint::from_u32(m[0] | 1));
+        }
+    }
+}
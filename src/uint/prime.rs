@@ -0,0 +1,568 @@
+#[cfg(feature = "rand")]
+use rand::{distributions::Uniform, Rng};
+
+use crate::modular::{mont_mul, neg_inv, r2_mod, r_mod};
+use crate::uint::Uint;
+
+/// Trial division is done by all primes below this limit.
+const SMALL_PRIME_LIMIT: u32 = 1000;
+const SMALL_PRIMES: [u32; 168] = small_primes();
+
+// all primes below `SMALL_PRIME_LIMIT`, using the sieve of Eratosthenes
+const fn small_primes<const N: usize>() -> [u32; N] {
+    let mut composite = [false; SMALL_PRIME_LIMIT as usize];
+    let mut primes = [0; N];
+    let mut count = 0;
+    let mut i = 2;
+    while i < SMALL_PRIME_LIMIT as usize {
+        if !composite[i] {
+            primes[count] = i as u32;
+            count += 1;
+            let mut j = i * i;
+            while j < SMALL_PRIME_LIMIT as usize {
+                composite[j] = true;
+                j += i;
+            }
+        }
+        i += 1;
+    }
+    assert!(count == N);
+    primes
+}
+
+impl<const S: usize> Uint<S>
+where
+    [(); S + 1]:,
+{
+    /// Returns the smallest prime factor of `self` below 1000, or `None` if there is none.
+    /// If `self` is a prime below 1000, `self` is returned.
+    pub fn small_factor(&self) -> Option<u32> {
+        SMALL_PRIMES
+            .iter()
+            .copied()
+            .find(|p| self.div_rem_u32(*p).1 == 0)
+    }
+
+    /// Tests whether `self` is a strong probable prime to the given base.
+    ///
+    /// Every prime passes this test, while at most a quarter of all bases let a composite number
+    /// pass. If `base` is a multiple of `self`, the test is inconclusive and `true` is returned.
+    pub fn is_strong_probable_prime(&self, base: Self) -> bool {
+        match self.trivial_primality() {
+            Some(result) => result,
+            None => Montgomery::new(*self).is_strong_probable_prime(base),
+        }
+    }
+
+    /// Tests whether `self` is prime using the Miller–Rabin test, with the first `rounds` primes
+    /// as bases.
+    ///
+    /// Since the bases are fixed, this is deterministic. With 13 rounds, there are no
+    /// pseudoprimes below 3.3 * 10^24, but there are composites passing any fixed number of rounds.
+    /// For numbers which might be chosen adversarially, use [`Uint::is_probable_prime`] or random bases.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rounds` is larger than 168
+    pub fn miller_rabin(&self, rounds: usize) -> bool {
+        assert!(
+            rounds <= SMALL_PRIMES.len(),
+            "at most {} rounds are supported",
+            SMALL_PRIMES.len()
+        );
+        if let Some(result) = self.trivial_primality() {
+            return result;
+        }
+        let mont = Montgomery::new(*self);
+        SMALL_PRIMES[..rounds]
+            .iter()
+            .all(|p| mont.is_strong_probable_prime(Self::from_u32(*p)))
+    }
+
+    /// Tests whether `self` is prime using the Miller–Rabin test with `rounds` random bases.
+    /// A composite number passes with a probability of at most 4^-rounds.
+    #[cfg(feature = "rand")]
+    pub fn miller_rabin_random<R: Rng + ?Sized>(&self, rounds: usize, rng: &mut R) -> bool {
+        if let Some(result) = self.trivial_primality() {
+            return result;
+        }
+        let mont = Montgomery::new(*self);
+        // 1 and -1 are useless as bases
+        let bases = Uniform::new(Self::TWO, *self - Self::ONE);
+        (0..rounds).all(|_| mont.is_strong_probable_prime(rng.sample(bases)))
+    }
+
+    /// Tests whether `self` is prime using the Baillie–PSW test: trial division, followed by a
+    /// Miller–Rabin test to base 2 and a strong Lucas test.
+    ///
+    /// There are no known composite numbers passing this test, and it is known to be correct for
+    /// all numbers below 2^64.
+    pub fn is_probable_prime(&self) -> bool {
+        if let Some(result) = self.trivial_primality() {
+            return result;
+        }
+        let mont = Montgomery::new(*self);
+        mont.is_strong_probable_prime(Self::TWO) && self.is_strong_lucas_probable_prime(&mont)
+    }
+
+    /// Returns the smallest prime larger than `self`, or `None` if it doesn't fit.
+    ///
+    /// The candidates are tested with [`Uint::is_probable_prime`].
+    pub fn next_prime(self) -> Option<Self> {
+        if self < Self::TWO {
+            return Some(Self::TWO);
+        }
+        // `Self::MAX` is odd, so this doesn't overflow
+        let mut candidate = match self.is_even() {
+            true => self + Self::ONE,
+            false => self.checked_add(Self::TWO)?,
+        };
+        while !candidate.is_probable_prime() {
+            candidate = candidate.checked_add(Self::TWO)?;
+        }
+        Some(candidate)
+    }
+
+    /// Returns the largest prime smaller than `self`, or `None` if `self <= 2`.
+    ///
+    /// The candidates are tested with [`Uint::is_probable_prime`].
+    pub fn prev_prime(self) -> Option<Self> {
+        if self <= Self::TWO {
+            return None;
+        }
+        if self == Self::from_u32(3) {
+            return Some(Self::TWO);
+        }
+        // 3 is prime, so this stops before reaching 1
+        let mut candidate = match self.is_even() {
+            true => self - Self::ONE,
+            false => self - Self::TWO,
+        };
+        while !candidate.is_probable_prime() {
+            candidate -= Self::TWO;
+        }
+        Some(candidate)
+    }
+
+    /// Generates a random prime with exactly `bits` bits, meaning its highest bit is `bits - 1`.
+    ///
+    /// The candidates are tested with [`Uint::is_probable_prime`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is smaller than 2, or larger than `Self::BITS`
+    #[cfg(feature = "rand")]
+    pub fn random_prime<R: Rng + ?Sized>(bits: u32, rng: &mut R) -> Self {
+        assert!(
+            (2..=Self::BITS).contains(&bits),
+            "a prime with {} bits does not exist or does not fit",
+            bits
+        );
+        loop {
+            let mut candidate = Self::random_bits(bits, rng);
+            candidate.set_bit(bits - 1);
+            candidate.set_bit(0);
+            if candidate.is_probable_prime() {
+                return candidate;
+            }
+        }
+    }
+
+    // decides the primality of numbers which are small, even or have a small factor.
+    // If `None` is returned, `self` is odd and larger than `SMALL_PRIME_LIMIT^2`.
+    fn trivial_primality(&self) -> Option<bool> {
+        if *self < Self::TWO {
+            return Some(false);
+        }
+        if let Some(p) = self.small_factor() {
+            return Some(*self == Self::from_u32(p));
+        }
+        if *self < Self::from_u32(SMALL_PRIME_LIMIT * SMALL_PRIME_LIMIT) {
+            // a composite number has a prime factor below its square root
+            return Some(true);
+        }
+        None
+    }
+
+    // the strong Lucas probable prime test, with the parameters chosen by Selfridge's method A:
+    // D is the first of 5, -7, 9, -11, ... with the Jacobi symbol (D/n) = -1, P = 1 and Q = (1 - D) / 4.
+    // `self` must be odd and must not have any small factors.
+    fn is_strong_lucas_probable_prime(&self, mont: &Montgomery<S>) -> bool {
+        let mut d: i64 = 5;
+        loop {
+            match jacobi(d, self) {
+                -1 => break,
+                // |d| is a factor, and it is smaller than `self` since there are no small factors
+                0 => return false,
+                _ => {}
+            }
+            // for perfect squares, there is no D with (D/n) = -1
+            if d == 13 && self.is_perfect_square() {
+                return false;
+            }
+            d = if d > 0 { -(d + 2) } else { -d + 2 };
+        }
+        let q = (1 - d) / 4;
+        let (d, q) = (mont.convert_signed(d), mont.convert_signed(q));
+
+        // n + 1 = k * 2^s with odd k. `self` is not `MAX`, since that is a multiple of 3.
+        let n_plus_one = *self + Self::ONE;
+        let s = n_plus_one.trailing_zeros();
+        let k = n_plus_one >> s as u64;
+
+        // calculate U_k, V_k and Q^k by going through the bits of k, starting with U_1 = V_1 = P = 1.
+        // U_2j = U_j * V_j, V_2j = V_j^2 - 2 * Q^j
+        // U_(j+1) = (P * U_j + V_j) / 2, V_(j+1) = (D * U_j + P * V_j) / 2
+        let (mut u, mut v, mut q_k) = (mont.one, mont.one, q);
+        for i in (0..k.bits() - 1).rev() {
+            u = mont.mul(u, v);
+            v = mont.sub(mont.mul(v, v), mont.add(q_k, q_k));
+            q_k = mont.mul(q_k, q_k);
+            if k.bit(i) {
+                (u, v) = (
+                    mont.half(mont.add(u, v)),
+                    mont.half(mont.add(mont.mul(d, u), v)),
+                );
+                q_k = mont.mul(q_k, q);
+            }
+        }
+
+        // n is a strong Lucas probable prime if U_k = 0, or V_(k * 2^r) = 0 for some r < s
+        if u == Self::ZERO || v == Self::ZERO {
+            return true;
+        }
+        for _ in 1..s {
+            v = mont.sub(mont.mul(v, v), mont.add(q_k, q_k));
+            q_k = mont.mul(q_k, q_k);
+            if v == Self::ZERO {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// calculates the Jacobi symbol (d/n) for an odd `n` and an odd `d` with |d| > 1.
+fn jacobi<const S: usize>(d: i64, n: &Uint<S>) -> i32 {
+    let a = d.unsigned_abs() as u32;
+    let n_mod_4 = n.as_limbs()[S - 1] & 3;
+    let mut result = 1;
+    // (-1/n) = (-1)^((n - 1) / 2)
+    if d < 0 && n_mod_4 == 3 {
+        result = -result;
+    }
+    // quadratic reciprocity: (a/n) = (n/a) * (-1)^((a - 1) / 2 * (n - 1) / 2)
+    if a & 3 == 3 && n_mod_4 == 3 {
+        result = -result;
+    }
+    result * jacobi_u32(n.div_rem_u32(a).1, a)
+}
+
+// calculates the Jacobi symbol (a/n) for an odd `n`
+fn jacobi_u32(mut a: u32, mut n: u32) -> i32 {
+    let mut result = 1;
+    while a != 0 {
+        // (2/n) = -1 iff n = 3 or 5 mod 8
+        while a.is_multiple_of(2) {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Arithmetic modulo an odd number in Montgomery form, like in [`crate::ModUint`], but with a
+/// modulus which is only known at runtime.
+struct Montgomery<const S: usize> {
+    n: Uint<S>,
+    neg_inv: u32,
+    // R mod n, which is 1 in Montgomery form
+    one: Uint<S>,
+    // R^2 mod n
+    r2: Uint<S>,
+}
+
+impl<const S: usize> Montgomery<S> {
+    fn new(n: Uint<S>) -> Self {
+        let neg_inv = neg_inv(n.as_limbs()[S - 1]);
+        let one = r_mod(n.as_limbs());
+        let r2 = r2_mod(n.as_limbs(), &one, neg_inv);
+        Montgomery {
+            n,
+            neg_inv,
+            one: Uint::new(one),
+            r2: Uint::new(r2),
+        }
+    }
+
+    // converts `x` to Montgomery form. `x` doesn't need to be reduced.
+    fn convert(&self, x: Uint<S>) -> Uint<S> {
+        self.mul(x, self.r2)
+    }
+
+    // converts a small signed number to Montgomery form. `|x|` must be less than `n`.
+    fn convert_signed(&self, x: i64) -> Uint<S> {
+        let magnitude = self.convert(Uint::from_u32(x.unsigned_abs() as u32));
+        match x < 0 {
+            true => self.sub(Uint::ZERO, magnitude),
+            false => magnitude,
+        }
+    }
+
+    fn mul(&self, a: Uint<S>, b: Uint<S>) -> Uint<S> {
+        Uint::new(mont_mul(
+            a.as_limbs(),
+            b.as_limbs(),
+            self.n.as_limbs(),
+            self.neg_inv,
+        ))
+    }
+
+    fn add(&self, a: Uint<S>, b: Uint<S>) -> Uint<S> {
+        a.ct_add_mod(b, self.n)
+    }
+
+    fn sub(&self, a: Uint<S>, b: Uint<S>) -> Uint<S> {
+        a.ct_sub_mod(b, self.n)
+    }
+
+    // calculates x / 2 mod n, which is x / 2 or (x + n) / 2
+    fn half(&self, x: Uint<S>) -> Uint<S> {
+        if x.is_even() {
+            return x >> 1;
+        }
+        let (sum, carry) = x.overflowing_add(self.n);
+        let mut half = sum >> 1;
+        if carry {
+            half.set_bit(Uint::<S>::BITS - 1);
+        }
+        half
+    }
+
+    // calculates base^exp for `base` in Montgomery form
+    fn pow(&self, base: Uint<S>, exp: Uint<S>) -> Uint<S> {
+        let mut result = self.one;
+        for i in (0..exp.bits()).rev() {
+            result = self.mul(result, result);
+            if exp.bit(i) {
+                result = self.mul(result, base);
+            }
+        }
+        result
+    }
+
+    fn is_strong_probable_prime(&self, base: Uint<S>) -> bool {
+        let base = self.convert(base);
+        if base == Uint::ZERO {
+            return true;
+        }
+
+        // n - 1 = d * 2^s with odd d
+        let n_minus_one = self.n - Uint::ONE;
+        let s = n_minus_one.trailing_zeros();
+        let d = n_minus_one >> s as u64;
+        let minus_one = self.n - self.one;
+
+        // n is a strong probable prime if base^d = 1, or base^(d * 2^r) = -1 for some r < s
+        let mut x = self.pow(base, d);
+        if x == self.one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = self.mul(x, x);
+            if x == minus_one {
+                return true;
+            }
+            if x == self.one {
+                // the square roots of 1 besides 1 and -1 only exist modulo composite numbers
+                return false;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{jacobi_u32, Montgomery, SMALL_PRIMES};
+    use crate::Uint;
+
+    // primality of all numbers below `N`
+    fn sieve<const N: usize>() -> [bool; N] {
+        let mut is_prime = [true; N];
+        is_prime[0] = false;
+        is_prime[1] = false;
+        for i in 2..N {
+            for j in (i * i..N).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+        is_prime
+    }
+
+    #[test]
+    fn small_primes() {
+        let is_prime = sieve::<1000>();
+        let expected: Vec<u32> = (0..1000).filter(|i| is_prime[*i as usize]).collect();
+        assert_eq!(SMALL_PRIMES[..], expected[..]);
+    }
+
+    #[test]
+    fn against_sieve() {
+        let is_prime = sieve::<30_000>();
+        for (i, is_prime) in is_prime.iter().enumerate() {
+            let v = Uint::<2>::from_u32(i as u32);
+            assert_eq!(v.is_probable_prime(), *is_prime, "{}", i);
+            assert_eq!(v.miller_rabin(3), *is_prime, "{}", i);
+        }
+    }
+
+    #[test]
+    fn jacobi() {
+        // (a/n) = a^((n - 1) / 2) mod n for primes n
+        for n in [3u32, 5, 7, 11, 13, 101, 997] {
+            for a in 0..2 * n {
+                let euler =
+                    Uint::<2>::from_u32(a).pow_mod(Uint::from_u32((n - 1) / 2), Uint::from_u32(n));
+                let expected = match euler.to_u128().unwrap() {
+                    0 => 0,
+                    1 => 1,
+                    _ => -1,
+                };
+                assert_eq!(jacobi_u32(a, n), expected);
+            }
+        }
+        assert_eq!(jacobi_u32(2, 15), 1);
+        assert_eq!(jacobi_u32(3, 15), 0);
+        assert_eq!(jacobi_u32(7, 15), -1);
+    }
+
+    #[test]
+    fn pseudoprimes() {
+        // strong pseudoprimes to base 2, which are caught by the Lucas test
+        for n in [
+            2047u64,
+            3277,
+            4033,
+            4681,
+            8321,
+            3_215_031_751,
+            2_152_302_898_747,
+        ] {
+            let n = Uint::<2>::from_u64(n);
+            let mont = Montgomery::new(n);
+            assert!(mont.is_strong_probable_prime(Uint::TWO));
+            assert!(!n.is_probable_prime());
+        }
+        // strong Lucas pseudoprimes, which are caught by the Miller-Rabin test
+        for n in [
+            5459u64, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+        ] {
+            let n = Uint::<2>::from_u64(n);
+            assert!(n.is_strong_lucas_probable_prime(&Montgomery::new(n)));
+            assert!(!Montgomery::new(n).is_strong_probable_prime(Uint::TWO));
+        }
+        // Carmichael numbers
+        for n in [
+            561u64,
+            41041,
+            825_265,
+            321_197_185,
+            5_394_826_801,
+            232_250_619_601,
+        ] {
+            assert!(!Uint::<2>::from_u64(n).is_probable_prime());
+            assert!(!Uint::<2>::from_u64(n).miller_rabin(13));
+        }
+        // the smallest numbers passing the first 12 and 13 prime bases
+        let n = Uint::<4>::from_u128(318_665_857_834_031_151_167_461);
+        assert!(n.miller_rabin(12));
+        assert!(!n.miller_rabin(13));
+        assert!(!n.is_probable_prime());
+        let n = Uint::<4>::from_u128(3_317_044_064_679_887_385_961_981);
+        assert!(n.miller_rabin(13));
+        assert!(!n.is_probable_prime());
+    }
+
+    #[test]
+    fn large_numbers() {
+        let mersenne = |p: u64| (Uint::<17>::ONE << p) - Uint::ONE;
+        for p in [61, 89, 107, 127, 521] {
+            let n = mersenne(p);
+            assert!(n.is_probable_prime());
+            assert!(n.miller_rabin(10));
+        }
+        for p in [67, 101, 257] {
+            assert!(!mersenne(p).is_probable_prime());
+        }
+
+        // the Fermat number 2^128 + 1, which is the product of two primes without any small
+        // factors. Like all composite Fermat numbers, it is a strong pseudoprime to base 2.
+        let p = Uint::<8>::from_u64(59_649_589_127_497_217);
+        let q = Uint::<8>::from_u128(5_704_689_200_685_129_054_721);
+        assert_eq!(p * q, (Uint::ONE << 128) + Uint::ONE);
+        assert!(p.is_probable_prime());
+        assert!(q.is_probable_prime());
+        assert!(!(p * q).is_probable_prime());
+        assert!((p * q).miller_rabin(1));
+        assert!(!(p * q).miller_rabin(2));
+        assert!(!(p * p).is_probable_prime());
+    }
+
+    #[test]
+    fn next_and_prev_prime() {
+        let is_prime = sieve::<5_000>();
+        let primes: Vec<u32> = (0..5_000).filter(|i| is_prime[*i as usize]).collect();
+        for i in 0..4_900u32 {
+            let next = primes.iter().find(|p| **p > i).copied();
+            let prev = primes.iter().rev().find(|p| **p < i).copied();
+            assert_eq!(
+                Uint::<1>::from_u32(i).next_prime(),
+                next.map(Uint::from_u32)
+            );
+            assert_eq!(
+                Uint::<1>::from_u32(i).prev_prime(),
+                prev.map(Uint::from_u32)
+            );
+        }
+
+        let two_64 = Uint::<4>::ONE << 64;
+        assert_eq!(two_64.next_prime(), Some(two_64 + Uint::from_u32(13)));
+        assert_eq!(two_64.prev_prime(), Some(two_64 - Uint::from_u32(59)));
+        assert_eq!(
+            Uint::<4>::MAX.prev_prime(),
+            Some(Uint::MAX - Uint::from_u32(158))
+        );
+        assert_eq!(Uint::<4>::MAX.next_prime(), None);
+        assert_eq!(Uint::<1>::from_u32(u32::MAX - 4).next_prime(), None);
+        assert_eq!(
+            Uint::<1>::from_u32(u32::MAX - 5).next_prime(),
+            Some(Uint::from_u32(u32::MAX - 4))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random() {
+        let mut rng = rand::thread_rng();
+        for bits in [2, 3, 17, 64, 100, 256] {
+            let p = Uint::<8>::random_prime(bits, &mut rng);
+            assert_eq!(p.bits(), bits);
+            assert!(p.is_probable_prime());
+            assert!(p.miller_rabin_random(20, &mut rng));
+        }
+        let composite = Uint::<8>::from_u64(3_215_031_751);
+        assert!(!composite.miller_rabin_random(20, &mut rng));
+    }
+}
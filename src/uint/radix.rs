@@ -0,0 +1,256 @@
+//! Conversion of numbers to base 10^9 on little-endian digit slices, like in `karatsuba`.
+//!
+//! Small numbers are converted by repeatedly dividing them by 10^9, which is a single pass over
+//! their digits. For large numbers, that becomes expensive since every pass touches the whole
+//! number, so they are instead split into a high and a low part by a long division by a power
+//! (10^9)^(2^k), and both parts are converted recursively.
+//! Every part of the number is kept in the part of the output where its chunks end up, so apart
+//! from the buffers passed in, no memory is required.
+
+use core::cmp::Ordering;
+
+use super::karatsuba;
+use super::slice::{add_in_place, cmp_shifted, shl_small_in_place, shr_small_in_place, trimmed};
+
+/// The base the numbers are converted to. This is the largest power of 10 which fits into a digit.
+pub(super) const CHUNK: u32 = 1_000_000_000;
+
+/// Numbers with this many digits or more are converted by splitting them.
+/// This was found by benchmarking - see `to_base_10_large` in `benches/ops.rs`.
+pub(super) const SPLIT_THRESHOLD: usize = 32;
+
+// A number is split by a power with at least half as many digits, so at least 16 digits since only
+// numbers with `SPLIT_THRESHOLD` digits are split. (10^9)^(2^k) has fewer than 16 digits for k < 5,
+// so these powers are never stored.
+const MIN_LEVEL: usize = 5;
+const MAX_LEVELS: usize = 32;
+
+/// Converts `x` to base 10^9, writing the chunks to `out` in little-endian order.
+/// Returns the number of chunks written, which is at least 1.
+///
+/// `out` must be able to hold all the chunks. `out`, `scratch` and `powers` must each hold
+/// at least `2 * x.len()` digits if `x` has at least `SPLIT_THRESHOLD` significant digits.
+pub(super) fn to_base_1e9(
+    x: &[u32],
+    out: &mut [u32],
+    scratch: &mut [u32],
+    powers: &mut [u32],
+) -> usize {
+    let x = trimmed(x);
+    if x.len() < SPLIT_THRESHOLD {
+        scratch[..x.len()].copy_from_slice(x);
+        return convert_simple(&mut scratch[..x.len()], out);
+    }
+
+    // x < (10^9)^(2^levels), so this is the top of the recursion
+    let powers = Powers::new(powers, x.len());
+    out[..x.len()].copy_from_slice(x);
+    out[x.len()..].fill(0);
+    convert(out, x.len(), powers.levels, false, &powers, scratch)
+}
+
+/// Converts the number stored in the first `len` digits of `region`, which is less than
+/// (10^9)^(2^level), writing its chunks back into `region`.
+///
+/// If `pad` is set, exactly `2^level` chunks are written, including leading zeros.
+/// Otherwise, `region` may be shorter than that and the leading zeros are omitted.
+fn convert(
+    region: &mut [u32],
+    len: usize,
+    level: usize,
+    pad: bool,
+    powers: &Powers,
+    scratch: &mut [u32],
+) -> usize {
+    let len = trimmed(&region[..len]).len();
+    if len < SPLIT_THRESHOLD {
+        scratch[..len].copy_from_slice(&region[..len]);
+        let written = convert_simple(&mut scratch[..len], region);
+        if !pad {
+            return written;
+        }
+        region[written..1 << level].fill(0);
+        return 1 << level;
+    }
+
+    // split x = q * p + r, where p = (10^9)^(2^(level-1)) and q, r < p
+    let half = 1 << (level - 1);
+    let (p, shift) = powers.get(level - 1);
+    let x = &region[..len];
+    if !pad && cmp_shifted(x, p, shift) == Ordering::Less {
+        return convert(region, len, level - 1, false, powers, scratch);
+    }
+
+    // divide the shifted x by the normalized p, leaving the remainder in the low `p.len()` digits
+    // and the quotient in the digits above.
+    let u = &mut scratch[..len.max(p.len()) + 1];
+    shl_into(u, x, shift);
+    div_rem_in_place(u, p);
+    shr_small_in_place(&mut u[..p.len()], shift);
+
+    // r and q both have at most `p.len() <= half` digits
+    let q_len = (u.len() - p.len()).min(p.len());
+    region.fill(0);
+    region[..p.len()].copy_from_slice(&u[..p.len()]);
+    region[half..half + q_len].copy_from_slice(&u[p.len()..p.len() + q_len]);
+
+    let (lo, hi) = region.split_at_mut(half);
+    convert(lo, p.len(), level - 1, true, powers, scratch);
+    half + convert(hi, q_len, level - 1, pad, powers, scratch)
+}
+
+/// Converts `x` by dividing it by 10^9 until it becomes zero. `x` is destroyed in the process.
+fn convert_simple(x: &mut [u32], out: &mut [u32]) -> usize {
+    let mut len = trimmed(x).len();
+    let mut written = 0;
+    loop {
+        out[written] = div_rem_chunk(&mut x[..len]);
+        written += 1;
+        while len > 0 && x[len - 1] == 0 {
+            len -= 1;
+        }
+        if len == 0 {
+            return written;
+        }
+    }
+}
+
+// `x /= 10^9`, returning the remainder.
+// since the divisor is a constant, the compiler can replace the divisions by multiplications.
+fn div_rem_chunk(x: &mut [u32]) -> u32 {
+    let mut rem = 0u64;
+    for digit in x.iter_mut().rev() {
+        let cur = (rem << 32) | *digit as u64;
+        *digit = (cur / CHUNK as u64) as u32;
+        rem = cur % CHUNK as u64;
+    }
+    rem as u32
+}
+
+/// The powers (10^9)^(2^k) for `MIN_LEVEL <= k < levels`, stored back to back.
+/// They are normalized, meaning they are shifted left until the highest bit of their most
+/// significant digit is set, which is required by `div_rem_in_place`.
+struct Powers<'a> {
+    digits: &'a [u32],
+    // (start, len, shift) of every power in `digits`
+    ranges: [(usize, usize, u32); MAX_LEVELS],
+    levels: usize,
+}
+
+impl<'a> Powers<'a> {
+    // computes all powers required to split numbers with `len` digits
+    fn new(buf: &'a mut [u32], len: usize) -> Self {
+        let mut ranges = [(0, 0, 0); MAX_LEVELS];
+
+        // (10^9)^(2^MIN_LEVEL) has 30 digits, so the small powers are calculated in here
+        let mut small = [0u32; 32];
+        small[0] = CHUNK;
+        let mut small_len = 1;
+        for _ in 0..MIN_LEVEL {
+            let mut square = [0u32; 32];
+            karatsuba::schoolbook(
+                &mut square[..2 * small_len],
+                &small[..small_len],
+                &small[..small_len],
+            );
+            small = square;
+            small_len = trimmed(&small).len();
+        }
+        buf[..small_len].copy_from_slice(&small[..small_len]);
+        ranges[MIN_LEVEL] = (0, small_len, 0);
+        let mut levels = MIN_LEVEL + 1;
+        let mut end = small_len;
+
+        // (10^9)^(2^(k+1)) has at least 2n - 1 digits if (10^9)^(2^k) has n, so the powers stop
+        // once the next one is certainly larger than every number with `len` digits.
+        while levels < MAX_LEVELS {
+            let (start, n, _) = ranges[levels - 1];
+            if 2 * n - 1 > len {
+                break;
+            }
+            let (prev, next) = buf[start..].split_at_mut(n);
+            karatsuba::schoolbook(&mut next[..2 * n], prev, prev);
+            let next_len = trimmed(&next[..2 * n]).len();
+            if next_len > len {
+                break;
+            }
+            ranges[levels] = (end, next_len, 0);
+            levels += 1;
+            end += next_len;
+        }
+
+        for (start, n, shift) in &mut ranges[MIN_LEVEL..levels] {
+            *shift = buf[*start + *n - 1].leading_zeros();
+            shl_small_in_place(&mut buf[*start..*start + *n], *shift);
+        }
+
+        Powers {
+            digits: buf,
+            ranges,
+            levels,
+        }
+    }
+
+    // returns the normalized (10^9)^(2^level) and the shift used to normalize it
+    fn get(&self, level: usize) -> (&[u32], u32) {
+        debug_assert!((MIN_LEVEL..self.levels).contains(&level));
+        let (start, len, shift) = self.ranges[level];
+        (&self.digits[start..start + len], shift)
+    }
+}
+
+/// Divides `u` by `v` in place, using Knuth's algorithm D.
+/// Afterwards, the low `v.len()` digits of `u` hold the remainder, and the digits above hold the
+/// quotient.
+///
+/// `v` must be normalized, `u` must be longer than `v` and its top digit must be smaller than the
+/// top digit of `v`.
+fn div_rem_in_place(u: &mut [u32], v: &[u32]) {
+    let m = v.len();
+    let v_top = v[m - 1] as u64;
+    let v_next = if m > 1 { v[m - 2] as u64 } else { 0 };
+    debug_assert!(v_top >= 1 << 31);
+    debug_assert!(u.len() > m && (u[u.len() - 1] as u64) < v_top);
+
+    for j in (0..u.len() - m).rev() {
+        // estimate the quotient digit from the top digits. The estimate is at most 2 too large,
+        // and almost always correct after this correction.
+        let num = ((u[j + m] as u64) << 32) | u[j + m - 1] as u64;
+        let mut q = num / v_top;
+        let mut r = num % v_top;
+        let u_next = if m > 1 { u[j + m - 2] as u64 } else { 0 };
+        while q > u32::MAX as u64 || q * v_next > ((r << 32) | u_next) {
+            q -= 1;
+            r += v_top;
+            if r > u32::MAX as u64 {
+                break;
+            }
+        }
+
+        // u[j..=j+m] -= q * v
+        let window = &mut u[j..=j + m];
+        let mut carry = 0u64;
+        let mut borrow = false;
+        for (digit, v) in window.iter_mut().zip(v) {
+            let product = *v as u64 * q + carry;
+            carry = product >> 32;
+            (*digit, borrow) = digit.borrowing_sub(product as u32, borrow);
+        }
+        (window[m], borrow) = window[m].borrowing_sub(carry as u32, borrow);
+
+        // q was still one too large, so v has to be added back
+        if borrow {
+            q -= 1;
+            add_in_place(window, v);
+        }
+        debug_assert_eq!(window[m], 0);
+        window[m] = q as u32;
+    }
+}
+
+// writes `x << shift` to `out`, which must be longer than `x`. The remaining digits are zeroed.
+fn shl_into(out: &mut [u32], x: &[u32], shift: u32) {
+    out.fill(0);
+    out[..x.len()].copy_from_slice(x);
+    shl_small_in_place(out, shift);
+}
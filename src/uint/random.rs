@@ -0,0 +1,158 @@
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::uint::Uint;
+
+impl<const SIZE: usize> Distribution<Uint<SIZE>> for Standard {
+    /// Generates a number which is uniformly distributed over all values of `Uint<SIZE>`.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uint<SIZE> {
+        let mut v = Uint::ZERO;
+        rng.fill(&mut v.digits[..]);
+        v
+    }
+}
+
+impl<const SIZE: usize> Uint<SIZE> {
+    /// Generates a number which is uniformly distributed in `0..2^bits`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `bits` is larger than `Self::BITS`
+    pub fn random_bits<R: Rng + ?Sized>(bits: u32, rng: &mut R) -> Self {
+        assert!(
+            bits <= Self::BITS,
+            "{} bits do not fit into Uint<{}>",
+            bits,
+            SIZE
+        );
+        if bits == 0 {
+            return Self::ZERO;
+        }
+        rng.gen::<Self>() >> (Self::BITS - bits) as u64
+    }
+}
+
+/// The sampler used by [`rand::Rng::gen_range`] and [`rand::distributions::Uniform`] for `Uint`.
+///
+/// Numbers are sampled by rejection sampling: random numbers with as many bits as the size of the
+/// range are generated until one lies within the range, so the result is unbiased.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformUint<const SIZE: usize> {
+    low: Uint<SIZE>,
+    // the largest offset from `low` which may be returned
+    max_offset: Uint<SIZE>,
+}
+
+impl<const SIZE: usize> UniformSampler for UniformUint<SIZE> {
+    type X = Uint<SIZE>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(low < high, "Uniform::new called with `low >= high`");
+        Self::new_inclusive(low, high - Uint::ONE)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(
+            low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+        UniformUint {
+            low,
+            max_offset: high - low,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        // at least half of the numbers with this many bits are accepted
+        let bits = self.max_offset.bits();
+        loop {
+            let offset = Uint::random_bits(bits, rng);
+            if offset <= self.max_offset {
+                return self.low + offset;
+            }
+        }
+    }
+}
+
+impl<const SIZE: usize> SampleUniform for Uint<SIZE> {
+    type Sampler = UniformUint<SIZE>;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distributions::Uniform;
+    use rand::Rng;
+
+    use crate::Uint;
+
+    #[test]
+    fn random_bits() {
+        let mut rng = rand::thread_rng();
+        assert_eq!(Uint::<4>::random_bits(0, &mut rng), Uint::ZERO);
+        for bits in [1, 31, 32, 33, 100, 128] {
+            let mut max_bits = 0;
+            for _ in 0..100 {
+                let v = Uint::<4>::random_bits(bits, &mut rng);
+                assert!(v.bits() <= bits);
+                max_bits = max_bits.max(v.bits());
+            }
+            assert_eq!(max_bits, bits);
+        }
+    }
+
+    #[test]
+    fn gen_range() {
+        let mut rng = rand::thread_rng();
+        let (low, high) = (Uint::<4>::from_u32(10), Uint::<4>::from_u32(15));
+        let mut seen = [false; 6];
+        for _ in 0..1_000 {
+            let v = rng.gen_range(low..high);
+            assert!(low <= v && v < high);
+            let v = rng.gen_range(low..=high);
+            assert!(low <= v && v <= high);
+            seen[(v - low).to_u128().unwrap() as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+
+        assert_eq!(rng.gen_range(low..low + Uint::ONE), low);
+        assert_eq!(rng.gen_range(Uint::<4>::MAX..=Uint::MAX), Uint::MAX);
+        rng.gen_range(Uint::<4>::ZERO..=Uint::MAX);
+
+        // a range just above a power of two, where about half of the samples are rejected
+        let high = (Uint::<4>::ONE << 100) + Uint::ONE;
+        for v in rng.sample_iter(Uniform::new(Uint::ZERO, high)).take(100) {
+            assert!(v < high);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_range() {
+        let v = Uint::<4>::from_u32(10);
+        rand::thread_rng().gen_range(v..v);
+    }
+
+    #[test]
+    fn distribution() {
+        // every bit is set in about half of the numbers
+        let mut counts = [0; 128];
+        for _ in 0..1_000 {
+            let v: Uint<4> = rand::random();
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += v.bit(i as u32) as u32;
+            }
+        }
+        assert!(counts.iter().all(|c| (350..650).contains(c)));
+    }
+}
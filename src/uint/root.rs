@@ -0,0 +1,140 @@
+use crate::uint::Uint;
+
+impl<const SIZE: usize> Uint<SIZE>
+where
+    [(); SIZE + 1]:,
+{
+    /// Returns the square root of the number, rounded down.
+    pub fn isqrt(self) -> Self {
+        if self < Self::TWO {
+            return self;
+        }
+
+        // newton iteration, starting with 2^ceil(bits / 2) >= sqrt(self).
+        // the sequence decreases until it reaches the floor of the square root.
+        let mut x = Self::ONE << self.bits().div_ceil(2) as u64;
+        loop {
+            let y = (x + self / x) >> 1;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Returns the square root of the number if it is a perfect square, and `None` otherwise.
+    pub fn checked_sqrt(self) -> Option<Self> {
+        let root = self.isqrt();
+        if root * root == self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if there is an integer `x` such that `x * x == self`.
+    pub fn is_perfect_square(self) -> bool {
+        self.checked_sqrt().is_some()
+    }
+
+    /// Returns the `n`th root of the number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n` is zero
+    pub fn nth_root(self, n: u32) -> Self {
+        assert_ne!(n, 0, "attempt to calculate the zeroth root");
+        if n == 1 || self < Self::TWO {
+            return self;
+        }
+        if n == 2 {
+            return self.isqrt();
+        }
+        let bits = self.bits();
+        if n >= bits {
+            // self < 2^bits <= 2^n
+            return Self::ONE;
+        }
+
+        // newton iteration, starting with 2^ceil(bits / n) >= root(self).
+        // x_{k+1} = ((n - 1) * x_k + self / x_k^(n - 1)) / n
+        let mut x = Self::ONE << bits.div_ceil(n) as u64;
+        loop {
+            // if x^(n - 1) does not fit, it's certainly bigger than self
+            let quotient = match x.checked_pow(n - 1) {
+                Some(p) => self / p,
+                None => Self::ZERO,
+            };
+            let y = (x * (n - 1) + quotient) / Uint::from_u32(n);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Returns `true` if there are integers `x` and `k >= 2` such that `x^k == self`.
+    pub fn is_perfect_power(self) -> bool {
+        if self < Self::TWO {
+            return true;
+        }
+        (2..self.bits()).any(|k| self.nth_root(k).checked_pow(k) == Some(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use rand::Rng;
+
+    use crate::Uint;
+
+    #[test]
+    fn isqrt_against_u128() {
+        fn check(a: u128) {
+            let my_a = Uint::<4>::from_u128(a);
+            assert_eq!(my_a.isqrt().to_u128(), Some(a.isqrt()));
+            let is_square = a.isqrt() * a.isqrt() == a;
+            assert_eq!(my_a.checked_sqrt().is_some(), is_square);
+            assert_eq!(my_a.is_perfect_square(), is_square);
+        }
+
+        for a in 0..1_000 {
+            check(a);
+        }
+        check(u128::MAX);
+        check(u64::MAX as u128 * u64::MAX as u128);
+        for _ in 0..1_000 {
+            check(rand::random());
+            let r = rand::random::<u64>() as u128;
+            check(r * r);
+            check(r * r - 1);
+        }
+    }
+
+    #[test]
+    fn nth_root_against_num_bigint() {
+        for _ in 0..100 {
+            let mut digits = [0u32; 8];
+            rand::thread_rng().fill(&mut digits[..]);
+            let a = Uint::<8>::new(digits);
+            for n in [1, 2, 3, 4, 5, 7, 16, 100, 255, 256, 257, 1000] {
+                assert_eq!(a.nth_root(n), BigUint::from(a).nth_root(n));
+            }
+        }
+        assert_eq!(Uint::<2>::MAX.nth_root(3), Uint::from_u32(2642245));
+        assert_eq!(Uint::<2>::ZERO.nth_root(3), Uint::ZERO);
+    }
+
+    #[test]
+    fn perfect_powers() {
+        assert!(Uint::<4>::ZERO.is_perfect_power());
+        assert!(Uint::<4>::ONE.is_perfect_power());
+        assert!(Uint::<4>::from_u32(8).is_perfect_power());
+        assert!(Uint::<4>::from_u128(3u128.pow(80)).is_perfect_power());
+        assert!(Uint::<4>::from_u128(1 << 127).is_perfect_power());
+        assert!(!Uint::<4>::from_u32(12).is_perfect_power());
+        assert!(!Uint::<4>::from_u128(3u128.pow(80) + 1).is_perfect_power());
+        assert!(!Uint::<4>::TWO.is_perfect_power());
+    }
+}
@@ -0,0 +1,145 @@
+//! Human-readable formats store a `Uint` as a decimal string, which can be read back with any of the
+//! prefixes `0x`, `0o` and `0b` as well, like in `FromStr`. Other formats store all `SIZE` digits in
+//! big-endian order, so the length doesn't depend on the value.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use crate::uint::Uint;
+
+impl<const SIZE: usize> Serialize for Uint<SIZE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return serializer.collect_str(self);
+        }
+        let mut tuple = serializer.serialize_tuple(SIZE)?;
+        for digit in &self.digits {
+            tuple.serialize_element(digit)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, const SIZE: usize> Deserialize<'de> for Uint<SIZE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StrVisitor)
+        } else {
+            deserializer.deserialize_tuple(SIZE, DigitsVisitor)
+        }
+    }
+}
+
+struct StrVisitor<const SIZE: usize>;
+
+impl<const SIZE: usize> Visitor<'_> for StrVisitor<SIZE> {
+    type Value = Uint<SIZE>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a string containing an integer which fits into Uint<{}>",
+            SIZE
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    // small numbers may be written without quotes
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        let low = Uint::<2>::from_u64(v);
+        if low.significant_digits() > SIZE {
+            return Err(E::invalid_value(de::Unexpected::Unsigned(v), &self));
+        }
+        Ok(low.resized())
+    }
+}
+
+struct DigitsVisitor<const SIZE: usize>;
+
+impl<'de, const SIZE: usize> Visitor<'de> for DigitsVisitor<SIZE> {
+    type Value = Uint<SIZE>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} big-endian 32-bit digits", SIZE)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = Uint::ZERO;
+        for i in 0..SIZE {
+            v.digits[i] = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uint;
+
+    #[test]
+    fn json() {
+        let v = Uint::<4>::from_u128(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, format!("\"{}\"", v));
+        assert_eq!(serde_json::from_str::<Uint<4>>(&json).unwrap(), v);
+        assert_eq!(
+            serde_json::from_str::<Uint<4>>(&format!("\"{:#x}\"", v)).unwrap(),
+            v
+        );
+        assert_eq!(
+            serde_json::from_str::<Uint<4>>(&format!("\"{:#b}\"", v)).unwrap(),
+            v
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Uint<4>>("\"0\"").unwrap(),
+            Uint::ZERO
+        );
+        assert_eq!(
+            serde_json::from_str::<Uint<4>>("12").unwrap(),
+            Uint::from_u32(12)
+        );
+        assert_eq!(
+            serde_json::from_str::<Uint<1>>("4294967295").unwrap(),
+            Uint::MAX
+        );
+        assert!(serde_json::from_str::<Uint<1>>("4294967296").is_err());
+        assert!(serde_json::from_str::<Uint<1>>("\"4294967296\"").is_err());
+        assert!(serde_json::from_str::<Uint<1>>("\"-1\"").is_err());
+        assert!(serde_json::from_str::<Uint<1>>("-1").is_err());
+    }
+
+    #[test]
+    fn binary() {
+        for v in [Uint::<3>::ZERO, Uint::MAX, Uint::new([1, 2, 3])] {
+            let bytes = bincode::serialize(&v).unwrap();
+            // only the digits are stored, without any length
+            assert_eq!(bytes.len(), 3 * 4);
+            assert_eq!(bincode::deserialize::<Uint<3>>(&bytes).unwrap(), v);
+        }
+        assert_eq!(
+            bincode::serialize(&Uint::<2>::from_u32(1)).unwrap(),
+            [0, 0, 0, 0, 1, 0, 0, 0]
+        );
+        let bytes = bincode::serialize(&Uint::<2>::MAX).unwrap();
+        assert!(bincode::deserialize::<Uint<3>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..100 {
+            let v = Uint::<8>::new(rand::random());
+            let json = serde_json::to_string(&v).unwrap();
+            assert_eq!(serde_json::from_str::<Uint<8>>(&json).unwrap(), v);
+            let bytes = bincode::serialize(&v).unwrap();
+            assert_eq!(bincode::deserialize::<Uint<8>>(&bytes).unwrap(), v);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Helpers on little-endian digit slices, shared by `karatsuba` and `radix`.
+//!
+//! Unlike `Uint`, all slices in here store their digits in little-endian order - `x[0]` is the LSD.
+
+use core::cmp::Ordering;
+
+// compares `x` and `y`, treating the shorter slice as if it was padded with 0s.
+pub(super) fn cmp_padded(x: &[u32], y: &[u32]) -> Ordering {
+    let len = x.len().max(y.len());
+    for i in (0..len).rev() {
+        let x = x.get(i).copied().unwrap_or(0);
+        let y = y.get(i).copied().unwrap_or(0);
+        match x.cmp(&y) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+    }
+    Ordering::Equal
+}
+
+// `a += b`, returning the carry. `a` must be at least as long as `b`.
+pub(super) fn add_in_place(a: &mut [u32], b: &[u32]) -> bool {
+    let mut carry = false;
+    for (i, l) in a.iter_mut().enumerate() {
+        let r = b.get(i).copied().unwrap_or(0);
+        if i >= b.len() && !carry {
+            break;
+        }
+        let (res, c) = l.carrying_add(r, carry);
+        *l = res;
+        carry = c;
+    }
+    carry
+}
+
+// `a -= b`, returning the borrow. `a` must be at least as long as `b`.
+pub(super) fn sub_in_place(a: &mut [u32], b: &[u32]) -> bool {
+    let mut borrow = false;
+    for (i, l) in a.iter_mut().enumerate() {
+        let r = b.get(i).copied().unwrap_or(0);
+        if i >= b.len() && !borrow {
+            break;
+        }
+        let (res, c) = l.borrowing_sub(r, borrow);
+        *l = res;
+        borrow = c;
+    }
+    borrow
+}
+
+// compares `x` with `p >> shift`, where `p` was shifted left by `shift` without overflowing.
+pub(super) fn cmp_shifted(x: &[u32], p: &[u32], shift: u32) -> Ordering {
+    if x.len() != p.len() {
+        return x.len().cmp(&p.len());
+    }
+    for i in (0..x.len()).rev() {
+        let p_digit = match shift {
+            0 => p[i],
+            _ => (p[i] >> shift) | p.get(i + 1).map_or(0, |hi| hi << (32 - shift)),
+        };
+        match x[i].cmp(&p_digit) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+    }
+    Ordering::Equal
+}
+
+// `x <<= shift`, where `shift < 32`. The bits shifted out of the top digit are lost.
+pub(super) fn shl_small_in_place(x: &mut [u32], shift: u32) {
+    if shift == 0 {
+        return;
+    }
+    for i in (0..x.len()).rev() {
+        let lower = if i > 0 { x[i - 1] >> (32 - shift) } else { 0 };
+        x[i] = (x[i] << shift) | lower;
+    }
+}
+
+// `x >>= shift`, where `shift < 32`.
+pub(super) fn shr_small_in_place(x: &mut [u32], shift: u32) {
+    if shift == 0 {
+        return;
+    }
+    for i in 0..x.len() {
+        let upper = x.get(i + 1).map_or(0, |hi| hi << (32 - shift));
+        x[i] = (x[i] >> shift) | upper;
+    }
+}
+
+// strips the leading zeros of `x`
+pub(super) fn trimmed(x: &[u32]) -> &[u32] {
+    let len = x.iter().rposition(|d| *d != 0).map_or(0, |i| i + 1);
+    &x[..len]
+}